@@ -18,6 +18,8 @@ pub use crate::zip::Zip;
 use std::fs::File;
 use std::path::Path;
 
+use reqwest::header::HeaderMap;
+
 /// Metadata describing whether an archive comes from a local or remote origin.
 #[derive(Copy, Clone)]
 pub enum Origin {
@@ -57,6 +59,16 @@ cfg_if::cfg_if! {
         pub fn fetch_native(url: &str, cache_file: &Path) -> Result<Box<Archive>, failure::Error> {
             Tarball::fetch(url, cache_file)
         }
+
+        /// Fetch a remote archive in the native OS-preferred format, sending the specified
+        /// headers with each request.
+        pub fn fetch_native_with_headers(
+            url: &str,
+            headers: &HeaderMap,
+            cache_file: &Path,
+        ) -> Result<Box<Archive>, failure::Error> {
+            Tarball::fetch_with_headers(url, headers, cache_file)
+        }
     } else if #[cfg(windows)] {
         /// Load an archive in the native OS-preferred format from the specified file.
         ///
@@ -74,6 +86,16 @@ cfg_if::cfg_if! {
         pub fn fetch_native(url: &str, cache_file: &Path) -> Result<Box<Archive>, failure::Error> {
             Zip::fetch(url, cache_file)
         }
+
+        /// Fetch a remote archive in the native OS-preferred format, sending the specified
+        /// headers with each request.
+        pub fn fetch_native_with_headers(
+            url: &str,
+            headers: &HeaderMap,
+            cache_file: &Path,
+        ) -> Result<Box<Archive>, failure::Error> {
+            Zip::fetch_with_headers(url, headers, cache_file)
+        }
     } else {
         compile_error!("Unsupported OS (expected 'unix' or 'windows').");
     }
@@ -11,6 +11,7 @@ use fs_utils::ensure_containing_dir_exists;
 use headers_011::Headers011;
 use progress_read::ProgressRead;
 use reqwest;
+use reqwest::header::HeaderMap;
 use reqwest::hyper_011::header::{AcceptRanges, ByteRangeSpec, ContentLength, Range, RangeUnit};
 use reqwest::Response;
 use tar;
@@ -69,7 +70,20 @@ impl Tarball {
     /// tarball that can be streamed (and that tees its data to a local
     /// file as it streams).
     pub fn fetch(url: &str, cache_file: &Path) -> Result<Box<Archive>, failure::Error> {
-        let response = reqwest::get(url)?;
+        Tarball::fetch_with_headers(url, &HeaderMap::new(), cache_file)
+    }
+
+    /// Initiate fetching of a tarball from the given URL, sending the specified headers
+    /// with each request (e.g. for authentication).
+    pub fn fetch_with_headers(
+        url: &str,
+        headers: &HeaderMap,
+        cache_file: &Path,
+    ) -> Result<Box<Archive>, failure::Error> {
+        let response = reqwest::Client::new()
+            .get(url)
+            .headers(headers.clone())
+            .send()?;
 
         if !response.status().is_success() {
             Err(super::HttpError {
@@ -79,7 +93,7 @@ impl Tarball {
 
         let compressed_size = content_length(&response)?;
         let uncompressed_size = match accepts_byte_ranges(&response) {
-            true => fetch_uncompressed_size(url, compressed_size),
+            true => fetch_uncompressed_size(url, headers, compressed_size),
             false => None,
         };
 
@@ -148,10 +162,11 @@ struct UnexpectedContentLengthError {
 /// of a gzip file from a URL. This makes two round-trips to the server but avoids
 /// downloading the entire gzip file. For very small files it's unlikely to be
 /// more efficient than simply downloading the entire file up front.
-fn fetch_isize(url: &str, len: u64) -> Result<[u8; 4], failure::Error> {
+fn fetch_isize(url: &str, headers: &HeaderMap, len: u64) -> Result<[u8; 4], failure::Error> {
     let client = reqwest::Client::new();
     let mut response = client
         .get(url)
+        .headers(headers.clone())
         .header_011(Range::Bytes(vec![ByteRangeSpec::FromTo(len - 4, len - 1)]))
         .send()?;
 
@@ -197,9 +212,9 @@ fn accepts_byte_ranges(response: &Response) -> bool {
 /// an extra round-trip to the server, so it's only more efficient than just
 /// downloading the file if the file is large enough that downloading it is
 /// slower than the extra round trips.
-fn fetch_uncompressed_size(url: &str, len: u64) -> Option<u64> {
+fn fetch_uncompressed_size(url: &str, headers: &HeaderMap, len: u64) -> Option<u64> {
     // if there is an error, we ignore it and return None, instead of failing
-    fetch_isize(url, len)
+    fetch_isize(url, headers, len)
         .ok()
         .map(|packed| unpack_isize(packed))
 }
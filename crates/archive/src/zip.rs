@@ -7,6 +7,7 @@ use std::path::Path;
 
 use progress_read::ProgressRead;
 use reqwest;
+use reqwest::header::HeaderMap;
 use verbatim::PathExt;
 use zip_rs::ZipArchive;
 
@@ -36,7 +37,20 @@ impl Zip {
     /// Initiate fetching of a Node zip archive from the given URL, returning
     /// a `Remote` data source.
     pub fn fetch(url: &str, cache_file: &Path) -> Result<Box<Archive>, failure::Error> {
-        let mut response = reqwest::get(url)?;
+        Zip::fetch_with_headers(url, &HeaderMap::new(), cache_file)
+    }
+
+    /// Initiate fetching of a Node zip archive from the given URL, sending the specified
+    /// headers with the request (e.g. for authentication).
+    pub fn fetch_with_headers(
+        url: &str,
+        headers: &HeaderMap,
+        cache_file: &Path,
+    ) -> Result<Box<Archive>, failure::Error> {
+        let mut response = reqwest::Client::new()
+            .get(url)
+            .headers(headers.clone())
+            .send()?;
 
         if !response.status().is_success() {
             Err(super::HttpError {
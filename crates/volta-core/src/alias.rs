@@ -0,0 +1,160 @@
+//! Provides aliases, which are extra shims bound to a specific version of Node or Yarn
+//! regardless of the project or default toolchain, e.g. `node16` for `node@16`.
+//!
+//! Aliases are recorded as bin configs, so the shim runs them like an installed package
+//! binary, except that the executable comes from the alias's platform image.
+
+use std::fmt;
+use std::fs;
+
+use crate::error::ErrorDetails;
+use crate::fs::{delete_file_error, dir_entry_match};
+use crate::path;
+use crate::platform::PlatformSpec;
+use crate::session::Session;
+use crate::shim;
+use crate::style::tool_version;
+use crate::tool::{BinConfig, Spec};
+use semver::Version;
+use volta_fail::{throw, Fallible, ResultExt};
+
+/// An alias for a specific version of a tool.
+pub struct Alias {
+    /// The name of the alias shim
+    pub name: String,
+    /// The aliased tool, `node` or `yarn`
+    pub tool: String,
+    /// The version the alias is bound to
+    pub version: Version,
+}
+
+impl From<BinConfig> for Alias {
+    fn from(config: BinConfig) -> Self {
+        Alias {
+            name: config.name,
+            tool: config.package,
+            version: config.version,
+        }
+    }
+}
+
+impl fmt::Display for Alias {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} -> {}",
+            self.name,
+            tool_version(&self.tool, &self.version)
+        )
+    }
+}
+
+/// Creates an alias with the given name for a Node or Yarn version, fetching that version if
+/// necessary. An existing alias with the same name is replaced.
+pub fn create(name: &str, spec: Spec, session: &mut Session) -> Fallible<Alias> {
+    if !shim::is_3p_shim(name) {
+        throw!(ErrorDetails::AliasBuiltInError {
+            name: name.to_string(),
+        });
+    }
+
+    let config_file = path::user_tool_bin_config(name)?;
+    if config_file.exists() {
+        let existing = BinConfig::from_file(config_file)?;
+        if !existing.alias {
+            throw!(ErrorDetails::AliasConflict {
+                name: name.to_string(),
+                package: existing.package,
+            });
+        }
+    }
+
+    let (tool, version, platform) = match spec {
+        Spec::Node(_) => {
+            let node: Version = spec.resolve(session)?.into();
+            let platform = PlatformSpec {
+                node_runtime: node.clone(),
+                npm: None,
+                yarn: None,
+            };
+            ("node", node, platform)
+        }
+        Spec::Yarn(_) => {
+            let yarn: Version = spec.resolve(session)?.into();
+            // Yarn needs a Node to run on, so the alias uses the current default
+            let default = session.user_platform()?.ok_or(ErrorDetails::NoPlatform)?;
+            let platform = PlatformSpec {
+                node_runtime: default.node_runtime.clone(),
+                npm: default.npm.clone(),
+                yarn: Some(yarn.clone()),
+            };
+            ("yarn", yarn, platform)
+        }
+        Spec::Npm(_) => throw!(ErrorDetails::CannotAliasTool { tool: "npm".into() }),
+        Spec::Package(tool, _) => throw!(ErrorDetails::CannotAliasTool { tool }),
+    };
+
+    platform.checkout(session)?;
+
+    BinConfig {
+        name: name.to_string(),
+        package: tool.to_string(),
+        version: version.clone(),
+        path: tool.to_string(),
+        platform,
+        loader: None,
+        alias: true,
+    }
+    .write()?;
+    shim::create(name)?;
+
+    Ok(Alias {
+        name: name.to_string(),
+        tool: tool.to_string(),
+        version,
+    })
+}
+
+/// Lists the aliases, sorted by name.
+pub fn list() -> Fallible<Vec<Alias>> {
+    let bin_config_dir = path::user_bin_dir()?;
+    if !bin_config_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut aliases = dir_entry_match(&bin_config_dir, |entry| {
+        BinConfig::from_file(entry.path())
+            .ok()
+            .filter(|config| config.alias)
+            .map(Alias::from)
+    })
+    .with_context(|_| ErrorDetails::ReadBinConfigDirError {
+        dir: bin_config_dir,
+    })?;
+
+    aliases.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(aliases)
+}
+
+/// Deletes an alias, removing its shim and bin config.
+pub fn delete(name: &str) -> Fallible<Alias> {
+    let config_file = path::user_tool_bin_config(name)?;
+    let config = if config_file.exists() {
+        BinConfig::from_file(config_file.clone())?
+    } else {
+        throw!(ErrorDetails::AliasNotFound {
+            name: name.to_string(),
+        });
+    };
+
+    if !config.alias {
+        throw!(ErrorDetails::AliasNotFound {
+            name: name.to_string(),
+        });
+    }
+
+    shim::delete(name)?;
+    fs::remove_file(&config_file).with_context(delete_file_error(&config_file))?;
+
+    Ok(Alias::from(config))
+}
@@ -0,0 +1,457 @@
+//! Provides operations on Volta's cache of fetched tools, which consists of the
+//! downloaded archives in the inventory and the images unpacked from them.
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime};
+
+use lazy_static::lazy_static;
+use log::debug;
+use regex::Regex;
+use semver::Version;
+use sha1::{Digest, Sha1};
+use walkdir::WalkDir;
+
+use crate::fs::{delete_file_error, ensure_dir_does_not_exist, read_file};
+use crate::path;
+use crate::permissions;
+use crate::session::Session;
+use crate::style::tool_version;
+use volta_fail::{Fallible, ResultExt};
+
+lazy_static! {
+    static ref PACKAGE_ARCHIVE: Regex = Regex::new(
+        r"(?x)
+        ^(?P<name>.+?)                  # package name
+        -
+        (?P<version>\d+\.\d+\.\d+.*)    # package version
+        \.tgz$
+        "
+    )
+    .unwrap();
+}
+
+/// The kinds of tools stored in the cache.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CacheTool {
+    Node,
+    Npm,
+    Yarn,
+    Packages,
+}
+
+impl FromStr for CacheTool {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "node" => Ok(CacheTool::Node),
+            "npm" => Ok(CacheTool::Npm),
+            "yarn" => Ok(CacheTool::Yarn),
+            "packages" => Ok(CacheTool::Packages),
+            _ => Err(format!(
+                "'{}' is not a cached tool (expected node, npm, yarn, or packages)",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for CacheTool {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            CacheTool::Node => "node",
+            CacheTool::Npm => "npm",
+            CacheTool::Yarn => "yarn",
+            CacheTool::Packages => "packages",
+        })
+    }
+}
+
+/// A single fetched version of a tool or package.
+pub struct CacheEntry {
+    pub tool: CacheTool,
+    /// The name of the tool or package.
+    pub name: String,
+    pub version: Version,
+    /// The files for this entry in the inventory, starting with the downloaded archive.
+    files: Vec<PathBuf>,
+    /// The image directory the archive was unpacked into.
+    image: PathBuf,
+}
+
+impl CacheEntry {
+    fn new(tool: CacheTool, name: &str, version: Version) -> Fallible<Self> {
+        let version_str = version.to_string();
+        let (files, image) = match tool {
+            CacheTool::Node => (
+                vec![
+                    path::node_inventory_dir()?.join(path::node_distro_file_name(&version_str)),
+                    path::node_npm_version_file(&version_str)?,
+                ],
+                path::node_image_root_dir()?.join(&version_str),
+            ),
+            CacheTool::Npm => (
+                vec![path::npm_inventory_dir()?.join(path::npm_distro_file_name(&version_str))],
+                path::npm_image_dir(&version_str)?,
+            ),
+            CacheTool::Yarn => (
+                vec![path::yarn_inventory_dir()?.join(path::yarn_distro_file_name(&version_str))],
+                path::yarn_image_dir(&version_str)?,
+            ),
+            CacheTool::Packages => (
+                vec![
+                    path::package_distro_file(name, &version_str)?,
+                    path::package_distro_shasum(name, &version_str)?,
+                ],
+                path::package_image_dir(name, &version_str)?,
+            ),
+        };
+
+        Ok(CacheEntry {
+            tool,
+            name: name.to_string(),
+            version,
+            files,
+            image,
+        })
+    }
+
+    fn archive(&self) -> &Path {
+        &self.files[0]
+    }
+
+    /// The time this entry was fetched, based on the archive or, if the archive is
+    /// missing, the image directory.
+    pub fn fetched(&self) -> Option<SystemTime> {
+        fs::metadata(self.archive())
+            .or_else(|_| fs::metadata(&self.image))
+            .and_then(|metadata| metadata.modified())
+            .ok()
+    }
+
+    /// The total size in bytes of the archive and image for this entry.
+    pub fn size(&self) -> u64 {
+        let files: u64 = self
+            .files
+            .iter()
+            .filter_map(|file| fs::metadata(file).ok())
+            .map(|metadata| metadata.len())
+            .sum();
+        files + dir_size(&self.image)
+    }
+
+    fn is_older_than(&self, age: Duration) -> bool {
+        match self.fetched().and_then(|time| time.elapsed().ok()) {
+            Some(elapsed) => elapsed > age,
+            None => false,
+        }
+    }
+
+    fn remove(&self) -> Fallible<()> {
+        for file in self.files.iter() {
+            permissions::check_path(file)?;
+        }
+        permissions::check_path(&self.image)?;
+
+        for file in self.files.iter() {
+            if file.exists() {
+                fs::remove_file(file).with_context(delete_file_error(file))?;
+            }
+        }
+        ensure_dir_does_not_exist(&self.image)
+    }
+}
+
+impl fmt::Display for CacheEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&tool_version(&self.name, &self.version))
+    }
+}
+
+/// The disk space used by the cache, broken down by category.
+pub struct CacheSize {
+    pub node: u64,
+    pub npm: u64,
+    pub yarn: u64,
+    pub packages: u64,
+    pub images: u64,
+}
+
+impl CacheSize {
+    pub fn total(&self) -> u64 {
+        self.node + self.npm + self.yarn + self.packages + self.images
+    }
+}
+
+/// Calculates the disk space used by the cache.
+pub fn size() -> Fallible<CacheSize> {
+    Ok(CacheSize {
+        node: dir_size(&path::node_inventory_dir()?) + dir_size(&path::node_cache_dir()?),
+        npm: dir_size(&path::npm_inventory_dir()?),
+        yarn: dir_size(&path::yarn_inventory_dir()?),
+        packages: dir_size(&path::package_inventory_dir()?),
+        images: dir_size(&path::image_dir()?),
+    })
+}
+
+/// Lists the fetched versions in the cache, optionally restricted to a single tool.
+pub fn entries(session: &Session, tool: Option<CacheTool>) -> Fallible<Vec<CacheEntry>> {
+    let inventory = session.inventory()?;
+    let included = |kind: CacheTool| tool.map_or(true, |tool| tool == kind);
+    let mut entries = Vec::new();
+
+    if included(CacheTool::Node) {
+        for version in inventory.node.versions.iter() {
+            entries.push(CacheEntry::new(CacheTool::Node, "node", version.clone())?);
+        }
+    }
+    if included(CacheTool::Npm) {
+        for version in inventory.npm.versions.iter() {
+            entries.push(CacheEntry::new(CacheTool::Npm, "npm", version.clone())?);
+        }
+    }
+    if included(CacheTool::Yarn) {
+        for version in inventory.yarn.versions.iter() {
+            entries.push(CacheEntry::new(CacheTool::Yarn, "yarn", version.clone())?);
+        }
+    }
+    if included(CacheTool::Packages) {
+        for (name, version) in package_archives()? {
+            entries.push(CacheEntry::new(CacheTool::Packages, &name, version)?);
+        }
+    }
+
+    Ok(entries)
+}
+
+/// The result of cleaning the cache.
+pub struct CleanReport {
+    /// The entries that were removed.
+    pub removed: Vec<CacheEntry>,
+    /// The entries that matched but were kept because they are installed.
+    pub in_use: Vec<CacheEntry>,
+    /// The disk space freed, in bytes.
+    pub freed: u64,
+}
+
+/// Removes fetched versions from the cache, optionally restricted to a single tool and to
+/// versions fetched longer ago than `older_than`.
+///
+/// Versions used by the default platform or by an installed package are never removed,
+/// since they would otherwise need to be downloaded again the next time they are run.
+/// Versions pinned by projects are removed and will be fetched again on demand.
+pub fn clean(
+    session: &Session,
+    tool: Option<CacheTool>,
+    older_than: Option<Duration>,
+) -> Fallible<CleanReport> {
+    let in_use = InUse::current(session)?;
+    let mut report = CleanReport {
+        removed: Vec::new(),
+        in_use: Vec::new(),
+        freed: 0,
+    };
+
+    for entry in entries(session, tool)? {
+        if let Some(age) = older_than {
+            if !entry.is_older_than(age) {
+                continue;
+            }
+        }
+
+        if in_use.contains(&entry) {
+            debug!("Keeping {} since it is in use", entry);
+            report.in_use.push(entry);
+            continue;
+        }
+
+        debug!("Removing {} from the cache", entry);
+        let size = entry.size();
+        entry.remove()?;
+        report.freed += size;
+        report.removed.push(entry);
+    }
+
+    Ok(report)
+}
+
+/// The result of verifying the cache.
+pub struct VerifyReport {
+    /// The entries whose archive matched the stored checksum.
+    pub verified: Vec<CacheEntry>,
+    /// The entries whose archive is missing or does not match the stored checksum.
+    pub corrupted: Vec<CacheEntry>,
+    /// The entries which have no stored checksum to verify against.
+    pub unchecked: Vec<CacheEntry>,
+}
+
+/// Re-checks the archives in the cache against their stored checksums.
+pub fn verify(session: &Session) -> Fallible<VerifyReport> {
+    let mut report = VerifyReport {
+        verified: Vec::new(),
+        corrupted: Vec::new(),
+        unchecked: Vec::new(),
+    };
+
+    for entry in entries(session, None)? {
+        // ISSUE(#134): Only packages currently have stored checksums
+        if entry.tool != CacheTool::Packages {
+            report.unchecked.push(entry);
+            continue;
+        }
+
+        // An unreadable checksum is treated like a mismatch, since the archive can't be trusted
+        match read_file(&entry.files[1]) {
+            Ok(Some(stored)) => match archive_shasum(entry.archive()) {
+                Ok(ref calculated) if calculated == stored.trim() => report.verified.push(entry),
+                _ => report.corrupted.push(entry),
+            },
+            Ok(None) => report.unchecked.push(entry),
+            Err(_) => report.corrupted.push(entry),
+        }
+    }
+
+    Ok(report)
+}
+
+/// The tool versions which are used by the user's toolchain.
+struct InUse {
+    node: BTreeSet<Version>,
+    npm: BTreeSet<Version>,
+    yarn: BTreeSet<Version>,
+    packages: BTreeSet<(String, Version)>,
+}
+
+impl InUse {
+    fn current(session: &Session) -> Fallible<Self> {
+        let mut in_use = InUse {
+            node: BTreeSet::new(),
+            npm: BTreeSet::new(),
+            yarn: BTreeSet::new(),
+            packages: BTreeSet::new(),
+        };
+
+        let platforms = session
+            .user_platform()?
+            .map(|platform| (*platform).clone())
+            .into_iter()
+            .chain(
+                session
+                    .inventory()?
+                    .packages
+                    .iter()
+                    .map(|config| config.platform.clone()),
+            );
+        for platform in platforms {
+            in_use.node.insert(platform.node_runtime);
+            in_use.npm.extend(platform.npm);
+            in_use.yarn.extend(platform.yarn);
+        }
+
+        for config in session.inventory()?.packages.iter() {
+            in_use
+                .packages
+                .insert((config.name.clone(), config.version.clone()));
+        }
+
+        Ok(in_use)
+    }
+
+    fn contains(&self, entry: &CacheEntry) -> bool {
+        match entry.tool {
+            CacheTool::Node => self.node.contains(&entry.version),
+            CacheTool::Npm => self.npm.contains(&entry.version),
+            CacheTool::Yarn => self.yarn.contains(&entry.version),
+            CacheTool::Packages => self
+                .packages
+                .contains(&(entry.name.clone(), entry.version.clone())),
+        }
+    }
+}
+
+/// Finds the name and version of each package archive in the package inventory, including
+/// scoped packages, which are stored in a subdirectory for the scope.
+fn package_archives() -> Fallible<Vec<(String, Version)>> {
+    let dir = path::package_inventory_dir()?;
+    let mut archives = Vec::new();
+
+    for entry in WalkDir::new(&dir).min_depth(1).max_depth(2) {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                debug!("{}", e);
+                continue;
+            }
+        };
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let relative = match entry.path().strip_prefix(&dir) {
+            Ok(relative) => relative.to_string_lossy().replace('\\', "/"),
+            Err(_) => continue,
+        };
+        if let Some(captures) = PACKAGE_ARCHIVE.captures(&relative) {
+            if let Ok(version) = Version::parse(&captures["version"]) {
+                archives.push((captures["name"].to_string(), version));
+            }
+        }
+    }
+
+    Ok(archives)
+}
+
+/// Calculates the sha1 checksum of an archive, in the format stored alongside it.
+fn archive_shasum(archive: &Path) -> io::Result<String> {
+    let mut buffer = Vec::new();
+    File::open(archive)?.read_to_end(&mut buffer)?;
+
+    let mut hasher = Sha1::new();
+    hasher.input(buffer);
+    Ok(hex::encode(&hasher.result()))
+}
+
+/// Calculates the total size of the files in a directory, which may not exist.
+fn dir_size(dir: &Path) -> u64 {
+    WalkDir::new(dir)
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| entry.metadata().ok())
+        .filter(|metadata| metadata.is_file())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{CacheTool, PACKAGE_ARCHIVE};
+
+    #[test]
+    fn test_parse_cache_tool() {
+        assert_eq!("node".parse::<CacheTool>(), Ok(CacheTool::Node));
+        assert_eq!("npm".parse::<CacheTool>(), Ok(CacheTool::Npm));
+        assert_eq!("yarn".parse::<CacheTool>(), Ok(CacheTool::Yarn));
+        assert_eq!("packages".parse::<CacheTool>(), Ok(CacheTool::Packages));
+        assert!("images".parse::<CacheTool>().is_err());
+    }
+
+    #[test]
+    fn test_package_archive_names() {
+        let captures = PACKAGE_ARCHIVE.captures("ember-cli-3.7.1.tgz").unwrap();
+        assert_eq!(&captures["name"], "ember-cli");
+        assert_eq!(&captures["version"], "3.7.1");
+
+        let captures = PACKAGE_ARCHIVE
+            .captures("@types/node-12.0.0-beta.1.tgz")
+            .unwrap();
+        assert_eq!(&captures["name"], "@types/node");
+        assert_eq!(&captures["version"], "12.0.0-beta.1");
+
+        assert!(PACKAGE_ARCHIVE.captures("ember-cli-3.7.1.shasum").is_none());
+    }
+}
@@ -4,6 +4,7 @@ use std::env;
 use std::path::{Path, PathBuf};
 
 pub const UNSAFE_GLOBAL: &'static str = "VOLTA_UNSAFE_GLOBAL";
+pub const PROJECT: &'static str = "VOLTA_PROJECT";
 
 pub(crate) fn shell_name() -> Option<String> {
     env::var_os("VOLTA_SHELL").map(|s| s.to_string_lossy().into_owned())
@@ -15,6 +16,12 @@ pub fn postscript_path() -> Option<PathBuf> {
         .map(|ref s| Path::new(s).to_path_buf())
 }
 
+pub(crate) fn project_dir() -> Option<PathBuf> {
+    env::var_os(PROJECT)
+        .filter(|s| !s.is_empty())
+        .map(PathBuf::from)
+}
+
 #[cfg(test)]
 pub mod tests {
 
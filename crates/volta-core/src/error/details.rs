@@ -3,11 +3,11 @@ use std::fmt;
 use std::path::PathBuf;
 
 use failure::Fail;
-use textwrap::{fill, indent};
+use textwrap::indent;
 
 use volta_fail::{ExitCode, VoltaFail};
 
-use crate::style::{text_width, tool_version};
+use crate::style::tool_version;
 use crate::tool;
 
 const REPORT_BUG_CTA: &'static str =
@@ -35,6 +35,22 @@ impl fmt::Display for CreatePostscriptErrorPath {
 
 #[derive(Debug, Fail, PartialEq)]
 pub enum ErrorDetails {
+    /// Thrown when the name of an alias is one of the built-in shims
+    AliasBuiltInError {
+        name: String,
+    },
+
+    /// Thrown when the name of an alias is already used by an executable from a package
+    AliasConflict {
+        name: String,
+        package: String,
+    },
+
+    /// Thrown when deleting an alias that does not exist
+    AliasNotFound {
+        name: String,
+    },
+
     /// Thrown when package tries to install a binary that is already installed.
     BinaryAlreadyInstalled {
         bin_name: String,
@@ -53,6 +69,11 @@ pub enum ErrorDetails {
     /// Thrown when building the virtual environment path fails
     BuildPathError,
 
+    /// Thrown when a user tries to `volta alias` something other than node/yarn.
+    CannotAliasTool {
+        tool: String,
+    },
+
     /// Thrown when a user tries to `volta pin` something other than node/yarn/npm.
     CannotPinPackage {
         package: String,
@@ -68,6 +89,11 @@ pub enum ErrorDetails {
         path: PathBuf,
     },
 
+    /// Thrown when archives in the cache do not match their stored checksums
+    CorruptedCacheArchives {
+        count: usize,
+    },
+
     CouldNotDetermineTool,
 
     CreateDirError {
@@ -111,6 +137,12 @@ pub enum ErrorDetails {
         bin: String,
     },
 
+    /// Thrown when a downloaded file does not match the checksum provided by a hook
+    DownloadChecksumMismatch {
+        tool: tool::Spec,
+        from_url: String,
+    },
+
     DownloadToolNetworkError {
         tool: tool::Spec,
         from_url: String,
@@ -131,15 +163,35 @@ pub enum ErrorDetails {
         command: String,
     },
 
+    /// Thrown when a directory being imported does not contain a Node installation
+    ExternalNodeNotFound {
+        path: PathBuf,
+    },
+
+    /// Thrown when the version of an external Node installation could not be determined
+    ExternalNodeVersionError {
+        path: PathBuf,
+    },
+
+    /// Thrown when `volta doctor --fix-permissions` could not repair the ownership of a path
+    FixPermissionsError {
+        path: PathBuf,
+    },
+
+    /// Thrown when a frozen import finds a version in the toolchain file that is not exact
+    FrozenVersionNotExact {
+        tool: String,
+    },
+
     /// Thrown when a hook command returns a non-zero exit code
     HookCommandFailed {
         command: String,
     },
 
-    /// Thrown when a hook contains multiple fields (prefix, template, or bin)
+    /// Thrown when a hook contains multiple fields (prefix, template, bin, or exec)
     HookMultipleFieldsSpecified,
 
-    /// Thrown when a hook doesn't contain any of the known fields (prefix, template, or bin)
+    /// Thrown when a hook doesn't contain any of the known fields (prefix, template, bin, or exec)
     HookNoFieldsSpecified,
 
     /// Thrown when determining the path to a hook fails
@@ -151,6 +203,12 @@ pub enum ErrorDetails {
         command: String,
     },
 
+    /// Thrown when an exec hook responds with a header that is not valid HTTP
+    InvalidHookHeader {
+        command: String,
+        header: String,
+    },
+
     /// Thrown when output from a hook command could not be read
     InvalidHookOutput {
         command: String,
@@ -170,11 +228,22 @@ pub enum ErrorDetails {
         errors: Vec<String>,
     },
 
+    /// Thrown when moving Volta's files into a different layout fails
+    LayoutMigrationError {
+        from: PathBuf,
+        to: PathBuf,
+    },
+
     /// Thrown when BinConfig (read from file) does not contain Platform info.
     NoBinPlatform {
         binary: String,
     },
 
+    /// Thrown when importing a Node version that is already available
+    NodeImageExists {
+        version: String,
+    },
+
     /// Thrown when there is no Node version matching a requested semver specifier.
     NodeVersionNotFound {
         matching: String,
@@ -209,8 +278,16 @@ pub enum ErrorDetails {
     /// Thrown when default Yarn is not set
     NoUserYarn,
 
+    /// Thrown when no version was chosen in the interactive picker
+    NoVersionSelected,
+
     NoVersionsFound,
 
+    /// Thrown when there is no npm version matching a requested semver specifier.
+    NpmVersionNotFound {
+        matching: String,
+    },
+
     /// Thrown when there is an error running `npm view`
     NpmViewError,
 
@@ -264,6 +341,11 @@ pub enum ErrorDetails {
     /// Thrown when unable to parse a bin config file
     ParseBinConfigError,
 
+    /// Thrown when unable to parse the JSON response of an exec hook
+    ParseHookResponseError {
+        command: String,
+    },
+
     /// Thrown when unable to parse a hooks.json file
     ParseHooksError {
         file: PathBuf,
@@ -280,6 +362,11 @@ pub enum ErrorDetails {
     /// Thrown when unable to parse the node index cache expiration
     ParseNodeIndexExpiryError,
 
+    /// Thrown when unable to parse the npm version index
+    ParseNpmIndexError {
+        from_url: String,
+    },
+
     /// Thrown when unable to parse the npm manifest file from a node install
     ParseNpmManifestError,
 
@@ -294,16 +381,36 @@ pub enum ErrorDetails {
     /// Thrown when unable to parse the platform.json file
     ParsePlatformError,
 
+    /// Thrown when a toolchain file could not be parsed
+    ParseSnapshotError {
+        file: PathBuf,
+    },
+
     /// Thrown when unable to parse a tool spec (`<tool>[@<version>]`)
     ParseToolSpecError {
         tool_spec: String,
     },
 
+    /// Thrown when a path in the Volta layout is owned by another user, e.g. after `sudo volta`
+    PathNotOwned {
+        path: PathBuf,
+    },
+
+    /// Thrown when a path in the Volta layout is not writable by its owner
+    PathNotWritable {
+        path: PathBuf,
+    },
+
     /// Thrown when persisting an archive to the inventory fails
     PersistInventoryError {
         tool: String,
     },
 
+    /// Thrown when the project directory given with `--project` or `VOLTA_PROJECT` does not exist
+    ProjectDirNotFound {
+        dir: PathBuf,
+    },
+
     /// Thrown when executing a project-local binary fails
     ProjectLocalBinaryExecError {
         command: String,
@@ -368,6 +475,16 @@ pub enum ErrorDetails {
         file: PathBuf,
     },
 
+    /// Thrown when there was an error reading the shim directory
+    ReadShimDirError {
+        dir: PathBuf,
+    },
+
+    /// Thrown when a toolchain file could not be read
+    ReadSnapshotError {
+        file: PathBuf,
+    },
+
     /// Thrown when the public registry for Node or Yarn could not be downloaded.
     RegistryFetchError {
         tool: String,
@@ -408,9 +525,19 @@ pub enum ErrorDetails {
     /// Thrown when serializing the platform to JSON fails
     StringifyPlatformError,
 
+    /// Thrown when serializing a toolchain snapshot fails
+    StringifySnapshotError,
+
     /// Thrown when serializing the toolchain to JSON fails
     StringifyToolchainError,
 
+    /// Thrown when `sudo volta doctor --fix-permissions` finds a Volta directory that does not
+    /// belong to the user who ran `sudo`
+    SudoUserLayoutError {
+        path: PathBuf,
+        home: PathBuf,
+    },
+
     /// Thrown when a given feature has not yet been implemented
     Unimplemented {
         feature: String,
@@ -457,6 +584,11 @@ pub enum ErrorDetails {
         file: PathBuf,
     },
 
+    /// Thrown when there was an error saving the selected layout
+    WriteLayoutFileError {
+        file: PathBuf,
+    },
+
     /// Thrown when there was an error writing a package config
     WritePackageConfigError {
         file: PathBuf,
@@ -488,6 +620,27 @@ pub enum ErrorDetails {
 impl fmt::Display for ErrorDetails {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            ErrorDetails::AliasBuiltInError { name } => write!(
+                f,
+                r#"Cannot create an alias named "{}", which is a built-in shim
+
+Please choose a different name for the alias."#,
+                name
+            ),
+            ErrorDetails::AliasConflict { name, package } => write!(
+                f,
+                "Executable '{}' is already installed by {}
+
+Please choose a different name for the alias, or remove {} first.",
+                name, package, package
+            ),
+            ErrorDetails::AliasNotFound { name } => write!(
+                f,
+                "Could not find alias '{}'
+
+Use `volta alias list` to see your aliases.",
+                name
+            ),
             ErrorDetails::BinaryAlreadyInstalled {
                 bin_name,
                 existing_package,
@@ -518,6 +671,13 @@ Use `volta install` to add a package to your toolchain (see `volta help install`
 
 Please ensure your PATH is valid."
             ),
+            ErrorDetails::CannotAliasTool { tool } => write!(
+                f,
+                "Only node and yarn can be aliased
+
+Use `volta install {}` to add it to your toolchain.",
+                tool
+            ),
             ErrorDetails::CannotPinPackage { package } => write!(
                 f,
                 "Only node and yarn can be pinned in a project
@@ -540,6 +700,13 @@ Please remove the file or pass `-f` or `--force` to override.",
                 path.display(),
                 PERMISSIONS_CTA
             ),
+            ErrorDetails::CorruptedCacheArchives { count } => write!(
+                f,
+                "{} cached archive(s) did not match the stored checksum.
+
+These packages will be downloaded again the next time they are installed.",
+                count
+            ),
             ErrorDetails::CouldNotDetermineTool => write!(
                 f,
                 "Could not determine tool name
@@ -614,6 +781,14 @@ at {}
 {}",
                 bin, REPORT_BUG_CTA
             ),
+            ErrorDetails::DownloadChecksumMismatch { tool, from_url } => write!(
+                f,
+                "Downloaded {} does not match the expected checksum
+from {}
+
+Please verify the checksum provided by your distro hook.",
+                tool, from_url
+            ),
             ErrorDetails::DownloadToolNetworkError { tool, from_url } => write!(
                 f,
                 "Could not download {}
@@ -643,6 +818,34 @@ Please verify your internet connection and ensure the correct version is specifi
 Please ensure that the correct command is specified.",
                 command
             ),
+            ErrorDetails::ExternalNodeNotFound { path } => write!(
+                f,
+                "Could not find a Node installation in {}
+
+Please ensure that the directory contains the `node` executable and the bundled npm.",
+                path.display()
+            ),
+            ErrorDetails::ExternalNodeVersionError { path } => write!(
+                f,
+                "Could not determine the version of the Node installation in {}
+
+Please ensure that the `node` executable can be run.",
+                path.display()
+            ),
+            ErrorDetails::FixPermissionsError { path } => write!(
+                f,
+                "Could not repair the permissions of {}
+
+Please ensure that you are running `volta doctor --fix-permissions` with `sudo`.",
+                path.display()
+            ),
+            ErrorDetails::FrozenVersionNotExact { tool } => write!(
+                f,
+                "Could not import {} with --frozen
+
+The toolchain file must list exact versions, so that none are resolved differently.",
+                tool
+            ),
             ErrorDetails::HookCommandFailed { command } => write!(
                 f,
                 "Hook command '{}' indicated a failure.
@@ -654,13 +857,13 @@ Please verify the requested tool and version.",
                 f,
                 "Hook configuration includes multiple hook types.
 
-Please include only one of 'bin', 'prefix', or 'template'"
+Please include only one of 'bin', 'exec', 'prefix', or 'template'"
             ),
             ErrorDetails::HookNoFieldsSpecified => write!(
                 f,
                 "Hook configuration includes no hook types.
 
-Please include one of 'bin', 'prefix', or 'template'"
+Please include one of 'bin', 'exec', 'prefix', or 'template'"
             ),
             ErrorDetails::HookPathError { command } => write!(
                 f,
@@ -676,6 +879,13 @@ Please ensure that the correct command is specified.",
 Please ensure that the correct command is specified.",
                 command
             ),
+            ErrorDetails::InvalidHookHeader { command, header } => write!(
+                f,
+                "Hook command '{}' returned an invalid HTTP header: '{}'
+
+Please ensure that header names and values are valid.",
+                command, header
+            ),
             ErrorDetails::InvalidHookOutput { command } => write!(
                 f,
                 "Could not read output from hook command: '{}'
@@ -705,21 +915,11 @@ To {action} the packages '{name}' and '{version}', please {action} them in separ
                     formatted=tool_version(name, version)
                 );
 
-                let wrapped_cta = match text_width() {
-                    Some(width) => fill(&call_to_action, width),
-                    None => call_to_action,
-                };
-
-                write!(f, "{}\n\n{}", error, wrapped_cta)
+                write!(f, "{}\n\n{}", error, call_to_action)
             }
 
             ErrorDetails::InvalidToolName { name, errors } => {
-                let indentation = "    ";
-                let wrapped = match text_width() {
-                    Some(width) => fill(&errors.join("\n"), width - indentation.len()),
-                    None => errors.join("\n"),
-                };
-                let formatted_errs = indent(&wrapped, indentation);
+                let formatted_errs = indent(&errors.join("\n"), "    ");
 
                 let call_to_action = if errors.len() > 1 {
                     "Please fix the following errors:"
@@ -734,6 +934,16 @@ To {action} the packages '{name}' and '{version}', please {action} them in separ
                 )
             }
 
+            ErrorDetails::LayoutMigrationError { from, to } => write!(
+                f,
+                "Could not move Volta's files
+from {}
+to {}
+
+Please ensure both directories are on the same filesystem and that you have the correct permissions.",
+                from.display(),
+                to.display()
+            ),
             ErrorDetails::NoBinPlatform { binary } => write!(
                 f,
                 "Platform info for executable `{}` is missing
@@ -741,6 +951,11 @@ To {action} the packages '{name}' and '{version}', please {action} them in separ
 Please uninstall and re-install the package that provides that executable.",
                 binary
             ),
+            ErrorDetails::NodeImageExists { version } => write!(
+                f,
+                "{} is already available, so an external installation can't be imported for it.",
+                version
+            ),
             ErrorDetails::NodeVersionNotFound { matching } => write!(
                 f,
                 r#"Could not find Node version matching "{}" in the version registry.
@@ -811,9 +1026,22 @@ Use `volta install` to select a default version of a tool."
                 "Yarn is not available.
 
 Use `volta install yarn` to select a default version (see `volta help install for more info)."
+            ),
+            ErrorDetails::NoVersionSelected => write!(
+                f,
+                "No version was selected.
+
+Please choose a version from the list, or run the command without `--interactive`."
             ),
             // No CTA as this error is purely informational
             ErrorDetails::NoVersionsFound => write!(f, "No tool versions found"),
+            ErrorDetails::NpmVersionNotFound { matching } => write!(
+                f,
+                r#"Could not find npm version matching "{}" in the version registry.
+
+Please verify that the version is correct."#,
+                matching
+            ),
             ErrorDetails::NpmViewError => write!(
                 f,
                 "Could not query package metadata.
@@ -907,6 +1135,13 @@ Please ensure you have correct permissions.",
 {}",
                 REPORT_BUG_CTA
             ),
+            ErrorDetails::ParseHookResponseError { command } => write!(
+                f,
+                "Could not parse the response from hook command: '{}'
+
+Please ensure that the command prints a JSON object with a 'url' field.",
+                command
+            ),
             ErrorDetails::ParseHooksError { file } => write!(
                 f,
                 "Could not parse hooks configuration file.
@@ -937,6 +1172,14 @@ Please verify your internet connection.",
 {}",
                 REPORT_BUG_CTA
             ),
+            ErrorDetails::ParseNpmIndexError { from_url } => write!(
+                f,
+                "Could not parse npm version index
+from {}
+
+Please verify your internet connection.",
+                from_url
+            ),
             ErrorDetails::ParseNpmManifestError => write!(
                 f,
                 "Could not parse package.json file for bundled npm.
@@ -965,6 +1208,14 @@ Please verify the requested package and version.",
 {}",
                 REPORT_BUG_CTA
             ),
+            ErrorDetails::ParseSnapshotError { file } => write!(
+                f,
+                "Could not parse toolchain file
+from {}
+
+Please ensure that the file was created by `volta export`.",
+                file.display()
+            ),
             ErrorDetails::ParseToolSpecError { tool_spec } => write!(
                 f,
                 "Could not parse tool spec `{}`
@@ -972,6 +1223,30 @@ Please verify the requested package and version.",
 Please supply a spec in the format `<tool name>[@<version>]`.",
                 tool_spec
             ),
+            ErrorDetails::PathNotOwned { path } => write!(
+                f,
+                "Could not modify {}
+because it is owned by another user.
+
+This usually happens after running Volta with `sudo`. To fix it, run:
+    sudo chown -R $USER {}
+or repair everything under the Volta home with:
+    sudo volta doctor --fix-permissions",
+                path.display(),
+                path.display()
+            ),
+            ErrorDetails::PathNotWritable { path } => write!(
+                f,
+                "Could not modify {}
+because it is not writable.
+
+To fix it, run:
+    chmod -R u+w {}
+or repair everything under the Volta home with:
+    volta doctor --fix-permissions",
+                path.display(),
+                path.display()
+            ),
             ErrorDetails::PersistInventoryError { tool } => write!(
                 f,
                 "Could not store {} archive in inventory cache
@@ -979,6 +1254,13 @@ Please supply a spec in the format `<tool name>[@<version>]`.",
 {}",
                 tool, PERMISSIONS_CTA
             ),
+            ErrorDetails::ProjectDirNotFound { dir } => write!(
+                f,
+                "Could not find project directory {}
+
+Please ensure that `--project` or VOLTA_PROJECT is set to an existing directory.",
+                dir.display()
+            ),
             ErrorDetails::ProjectLocalBinaryExecError { command } => write!(
                 f,
                 "Could not execute `{}`
@@ -1088,6 +1370,24 @@ from {}
                 "Could not read default platform file
 from {}
 
+{}",
+                file.display(),
+                PERMISSIONS_CTA
+            ),
+            ErrorDetails::ReadShimDirError { dir } => write!(
+                f,
+                "Could not read shim directory
+at {}
+
+{}",
+                dir.display(),
+                PERMISSIONS_CTA
+            ),
+            ErrorDetails::ReadSnapshotError { file } => write!(
+                f,
+                "Could not read toolchain file
+from {}
+
 {}",
                 file.display(),
                 PERMISSIONS_CTA
@@ -1153,6 +1453,13 @@ Please re-install Volta to create the shim file."
                 f,
                 "Could not serialize platform settings.
 
+{}",
+                REPORT_BUG_CTA
+            ),
+            ErrorDetails::StringifySnapshotError => write!(
+                f,
+                "Could not serialize toolchain snapshot.
+
 {}",
                 REPORT_BUG_CTA
             ),
@@ -1163,6 +1470,15 @@ Please re-install Volta to create the shim file."
 {}",
                 REPORT_BUG_CTA
             ),
+            ErrorDetails::SudoUserLayoutError { path, home } => write!(
+                f,
+                "Could not repair {}
+because it is not in {}, the home directory of the user who ran `sudo`.
+
+Please ensure that VOLTA_HOME is not set to another user's Volta directory.",
+                path.display(),
+                home.display()
+            ),
             ErrorDetails::Unimplemented { feature } => {
                 write!(f, "{} is not supported yet.", feature)
             }
@@ -1226,6 +1542,15 @@ to {}
                 "Could not write Node index cache expiration
 to {}
 
+{}",
+                file.display(),
+                PERMISSIONS_CTA
+            ),
+            ErrorDetails::WriteLayoutFileError { file } => write!(
+                f,
+                "Could not save layout settings
+to {}
+
 {}",
                 file.display(),
                 PERMISSIONS_CTA
@@ -1285,13 +1610,18 @@ Please verify that the version is correct."#,
 impl VoltaFail for ErrorDetails {
     fn exit_code(&self) -> ExitCode {
         match self {
+            ErrorDetails::AliasBuiltInError { .. } => ExitCode::InvalidArguments,
+            ErrorDetails::AliasConflict { .. } => ExitCode::InvalidArguments,
+            ErrorDetails::AliasNotFound { .. } => ExitCode::InvalidArguments,
             ErrorDetails::BinaryAlreadyInstalled { .. } => ExitCode::FileSystemError,
             ErrorDetails::BinaryExecError => ExitCode::ExecutionFailure,
             ErrorDetails::BinaryNotFound { .. } => ExitCode::ExecutableNotFound,
             ErrorDetails::BuildPathError => ExitCode::EnvironmentError,
+            ErrorDetails::CannotAliasTool { .. } => ExitCode::InvalidArguments,
             ErrorDetails::CannotPinPackage { .. } => ExitCode::InvalidArguments,
             ErrorDetails::CompletionsOutFileError { .. } => ExitCode::InvalidArguments,
             ErrorDetails::ContainingDirError { .. } => ExitCode::FileSystemError,
+            ErrorDetails::CorruptedCacheArchives { .. } => ExitCode::FileSystemError,
             ErrorDetails::CouldNotDetermineTool => ExitCode::UnknownError,
             ErrorDetails::CreateDirError { .. } => ExitCode::FileSystemError,
             ErrorDetails::CreatePostscriptError { .. } => ExitCode::FileSystemError,
@@ -1302,19 +1632,27 @@ impl VoltaFail for ErrorDetails {
             ErrorDetails::DeleteFileError { .. } => ExitCode::FileSystemError,
             ErrorDetails::DeprecatedCommandError { .. } => ExitCode::InvalidArguments,
             ErrorDetails::DetermineBinaryLoaderError { .. } => ExitCode::FileSystemError,
+            ErrorDetails::DownloadChecksumMismatch { .. } => ExitCode::NetworkError,
             ErrorDetails::DownloadToolNetworkError { .. } => ExitCode::NetworkError,
             ErrorDetails::ExecutablePathError { .. } => ExitCode::UnknownError,
             ErrorDetails::ExecutablePermissionsError { .. } => ExitCode::FileSystemError,
             ErrorDetails::ExecuteHookError { .. } => ExitCode::ExecutionFailure,
+            ErrorDetails::ExternalNodeNotFound { .. } => ExitCode::InvalidArguments,
+            ErrorDetails::ExternalNodeVersionError { .. } => ExitCode::ExecutionFailure,
+            ErrorDetails::FixPermissionsError { .. } => ExitCode::FileSystemError,
+            ErrorDetails::FrozenVersionNotExact { .. } => ExitCode::ConfigurationError,
             ErrorDetails::HookCommandFailed { .. } => ExitCode::ConfigurationError,
             ErrorDetails::HookMultipleFieldsSpecified => ExitCode::ConfigurationError,
             ErrorDetails::HookNoFieldsSpecified => ExitCode::ConfigurationError,
             ErrorDetails::HookPathError { .. } => ExitCode::ConfigurationError,
             ErrorDetails::InvalidHookCommand { .. } => ExitCode::ExecutableNotFound,
+            ErrorDetails::InvalidHookHeader { .. } => ExitCode::ExecutionFailure,
             ErrorDetails::InvalidHookOutput { .. } => ExitCode::ExecutionFailure,
             ErrorDetails::InvalidInvocation { .. } => ExitCode::InvalidArguments,
             ErrorDetails::InvalidToolName { .. } => ExitCode::InvalidArguments,
+            ErrorDetails::LayoutMigrationError { .. } => ExitCode::FileSystemError,
             ErrorDetails::NoBinPlatform { .. } => ExitCode::ExecutionFailure,
+            ErrorDetails::NodeImageExists { .. } => ExitCode::InvalidArguments,
             ErrorDetails::NodeVersionNotFound { .. } => ExitCode::NoVersionMatch,
             ErrorDetails::NoGlobalInstalls { .. } => ExitCode::InvalidArguments,
             ErrorDetails::NoHomeEnvironmentVar => ExitCode::EnvironmentError,
@@ -1326,7 +1664,9 @@ impl VoltaFail for ErrorDetails {
             ErrorDetails::NoProjectYarn => ExitCode::ConfigurationError,
             ErrorDetails::NotInPackage => ExitCode::ConfigurationError,
             ErrorDetails::NoUserYarn => ExitCode::ConfigurationError,
+            ErrorDetails::NoVersionSelected => ExitCode::InvalidArguments,
             ErrorDetails::NoVersionsFound => ExitCode::NoVersionMatch,
+            ErrorDetails::NpmVersionNotFound { .. } => ExitCode::NoVersionMatch,
             ErrorDetails::NpmViewError => ExitCode::NetworkError,
             ErrorDetails::NpmViewMetadataFetchError => ExitCode::NetworkError,
             ErrorDetails::NpmViewMetadataParseError { .. } => ExitCode::UnknownError,
@@ -1340,16 +1680,22 @@ impl VoltaFail for ErrorDetails {
             ErrorDetails::PackageVersionNotFound { .. } => ExitCode::NoVersionMatch,
             ErrorDetails::PackageWriteError { .. } => ExitCode::FileSystemError,
             ErrorDetails::ParseBinConfigError => ExitCode::UnknownError,
+            ErrorDetails::ParseHookResponseError { .. } => ExitCode::ExecutionFailure,
             ErrorDetails::ParseHooksError { .. } => ExitCode::ConfigurationError,
             ErrorDetails::ParseToolSpecError { .. } => ExitCode::InvalidArguments,
             ErrorDetails::ParseNodeIndexCacheError => ExitCode::UnknownError,
             ErrorDetails::ParseNodeIndexError { .. } => ExitCode::NetworkError,
             ErrorDetails::ParseNodeIndexExpiryError => ExitCode::UnknownError,
+            ErrorDetails::ParseNpmIndexError { .. } => ExitCode::NetworkError,
             ErrorDetails::ParseNpmManifestError => ExitCode::UnknownError,
             ErrorDetails::ParsePackageConfigError => ExitCode::UnknownError,
             ErrorDetails::ParsePackageMetadataError { .. } => ExitCode::UnknownError,
             ErrorDetails::ParsePlatformError => ExitCode::ConfigurationError,
+            ErrorDetails::ParseSnapshotError { .. } => ExitCode::ConfigurationError,
+            ErrorDetails::PathNotOwned { .. } => ExitCode::FileSystemError,
+            ErrorDetails::PathNotWritable { .. } => ExitCode::FileSystemError,
             ErrorDetails::PersistInventoryError { .. } => ExitCode::FileSystemError,
+            ErrorDetails::ProjectDirNotFound { .. } => ExitCode::InvalidArguments,
             ErrorDetails::ProjectLocalBinaryExecError { .. } => ExitCode::ExecutionFailure,
             ErrorDetails::ProjectLocalBinaryNotFound { .. } => ExitCode::FileSystemError,
             ErrorDetails::PublishHookBothUrlAndBin => ExitCode::ConfigurationError,
@@ -1364,6 +1710,8 @@ impl VoltaFail for ErrorDetails {
             ErrorDetails::ReadNpmManifestError => ExitCode::UnknownError,
             ErrorDetails::ReadPackageConfigError { .. } => ExitCode::FileSystemError,
             ErrorDetails::ReadPlatformError { .. } => ExitCode::FileSystemError,
+            ErrorDetails::ReadShimDirError { .. } => ExitCode::FileSystemError,
+            ErrorDetails::ReadSnapshotError { .. } => ExitCode::FileSystemError,
             ErrorDetails::RegistryFetchError { .. } => ExitCode::NetworkError,
             ErrorDetails::SetupToolImageError { .. } => ExitCode::FileSystemError,
             ErrorDetails::ShimCreateError { .. } => ExitCode::FileSystemError,
@@ -1373,7 +1721,9 @@ impl VoltaFail for ErrorDetails {
             ErrorDetails::StringifyBinConfigError => ExitCode::UnknownError,
             ErrorDetails::StringifyPackageConfigError => ExitCode::UnknownError,
             ErrorDetails::StringifyPlatformError => ExitCode::UnknownError,
+            ErrorDetails::StringifySnapshotError => ExitCode::UnknownError,
             ErrorDetails::StringifyToolchainError => ExitCode::UnknownError,
+            ErrorDetails::SudoUserLayoutError { .. } => ExitCode::EnvironmentError,
             ErrorDetails::Unimplemented { .. } => ExitCode::UnknownError,
             ErrorDetails::UnpackArchiveError { .. } => ExitCode::UnknownError,
             ErrorDetails::UnrecognizedShell { .. } => ExitCode::EnvironmentError,
@@ -1384,6 +1734,7 @@ impl VoltaFail for ErrorDetails {
             ErrorDetails::WriteDefaultNpmError { .. } => ExitCode::FileSystemError,
             ErrorDetails::WriteNodeIndexCacheError { .. } => ExitCode::FileSystemError,
             ErrorDetails::WriteNodeIndexExpiryError { .. } => ExitCode::FileSystemError,
+            ErrorDetails::WriteLayoutFileError { .. } => ExitCode::FileSystemError,
             ErrorDetails::WritePackageConfigError { .. } => ExitCode::FileSystemError,
             ErrorDetails::WritePackageShasumError { .. } => ExitCode::FileSystemError,
             ErrorDetails::WritePlatformError { .. } => ExitCode::FileSystemError,
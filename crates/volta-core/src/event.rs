@@ -100,6 +100,11 @@ impl EventLog {
         EventLog { events: Vec::new() }
     }
 
+    /// Returns whether no events have been recorded, in which case there is nothing to publish
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
     pub fn add_event_start(&mut self, activity_kind: ActivityKind) {
         self.add_event(EventKind::Start, activity_kind)
     }
@@ -151,9 +156,11 @@ pub mod tests {
     fn test_adding_events() {
         let mut event_log = EventLog::new();
         assert_eq!(event_log.events.len(), 0);
+        assert!(event_log.is_empty());
 
         event_log.add_event_start(ActivityKind::Current);
         assert_eq!(event_log.events.len(), 1);
+        assert!(!event_log.is_empty());
         assert_eq!(event_log.events[0].name, "current");
 
         event_log.add_event_end(ActivityKind::Pin, ExitCode::NetworkError);
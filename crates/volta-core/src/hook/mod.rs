@@ -1,12 +1,12 @@
 //! Provides types for working with Volta hooks.
 
-use std::env;
-use std::fs::File;
+use std::fs::read_to_string;
 use std::marker::PhantomData;
 use std::path::Path;
 
 use crate::error::ErrorDetails;
 use crate::path::{find_project_dir, user_hooks_file};
+use crate::project;
 use crate::tool::{Node, Package, Tool, Yarn};
 use lazycell::LazyCell;
 use log::debug;
@@ -42,6 +42,19 @@ impl LazyHookConfig {
     pub fn get(&self) -> Fallible<&HookConfig> {
         self.settings.try_borrow_with(|| HookConfig::current())
     }
+
+    /// Returns the hook for publishing events, if one is configured.
+    ///
+    /// Unless the configuration has already been loaded, this first checks that there is a
+    /// hooks file to load it from, so that running a tool without any hooks never loads it.
+    pub fn publish(&self) -> Fallible<Option<&Publish>> {
+        if !self.settings.filled() && !HookConfig::files_exist()? {
+            return Ok(None);
+        }
+
+        let hooks = self.get()?;
+        Ok(hooks.events().and_then(|events| events.publish.as_ref()))
+    }
 }
 
 /// Volta hook configuration
@@ -107,7 +120,7 @@ impl HookConfig {
     /// Returns the current hooks, which are a merge between the user hooks and
     /// the project hooks (if any).
     fn current() -> Fallible<Self> {
-        let maybe_project_config = Self::for_current_dir()?;
+        let maybe_project_config = Self::for_base_dir()?;
         let maybe_user_config = Self::for_user()?;
 
         Ok(match (maybe_project_config, maybe_user_config) {
@@ -129,9 +142,21 @@ impl HookConfig {
         })
     }
 
-    /// Returns the per-project hooks for the current directory.
-    fn for_current_dir() -> Fallible<Option<Self>> {
-        Self::for_dir(&env::current_dir().with_context(|_| ErrorDetails::CurrentDirError)?)
+    /// Checks whether there is a user or project hooks file to load the configuration from.
+    fn files_exist() -> Fallible<bool> {
+        if user_hooks_file()?.is_file() {
+            return Ok(true);
+        }
+
+        let base_dir = project::base_dir()?;
+        Ok(find_project_dir(&base_dir)
+            .map(|project_dir| project_dir.join(".volta").join("hooks.json").is_file())
+            .unwrap_or(false))
+    }
+
+    /// Returns the per-project hooks for the project base directory.
+    fn for_base_dir() -> Fallible<Option<Self>> {
+        Self::for_dir(&project::base_dir()?)
     }
 
     /// Returns the per-project hooks for the specified directory.  If the
@@ -158,13 +183,16 @@ impl HookConfig {
             return Ok(None);
         }
 
-        let file = File::open(file_path).with_context(|_| ErrorDetails::ReadHooksError {
-            file: file_path.to_path_buf(),
-        })?;
+        let contents =
+            read_to_string(file_path).with_context(|_| ErrorDetails::ReadHooksError {
+                file: file_path.to_path_buf(),
+            })?;
 
         let raw: serial::RawHookConfig =
-            serde_json::de::from_reader(file).with_context(|_| ErrorDetails::ParseHooksError {
-                file: file_path.to_path_buf(),
+            serde_json::de::from_str(&contents).with_context(|_| {
+                ErrorDetails::ParseHooksError {
+                    file: file_path.to_path_buf(),
+                }
             })?;
 
         let hooks_path = file_path.parent().unwrap_or(Path::new("/"));
@@ -291,6 +319,39 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_from_str_execs() {
+        let fixture_dir = fixture_path("hooks");
+        let exec_file = fixture_dir.join("execs.json");
+        let hooks = HookConfig::from_file(&exec_file).unwrap().unwrap();
+        let node = hooks.node.unwrap();
+        let package = hooks.package.unwrap();
+
+        assert_eq!(
+            node.distro,
+            Some(tool::DistroHook::Exec {
+                exec: "./node-mirror distro".to_string(),
+                base_path: fixture_dir.clone(),
+            })
+        );
+        assert_eq!(node.latest, None);
+        assert_eq!(
+            node.index,
+            Some(tool::MetadataHook::Exec {
+                exec: "./node-mirror index".to_string(),
+                base_path: fixture_dir.clone(),
+            })
+        );
+        assert_eq!(
+            package.index,
+            Some(tool::MetadataHook::Exec {
+                exec: "/usr/local/bin/registry-auth".to_string(),
+                base_path: fixture_dir.clone(),
+            })
+        );
+        assert!(hooks.yarn.is_none());
+    }
+
     #[test]
     fn test_from_str_prefixes() {
         let fixture_dir = fixture_path("hooks");
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::convert::{TryFrom, TryInto};
 use std::marker::PhantomData;
 use std::path::Path;
@@ -5,14 +6,25 @@ use std::path::Path;
 use super::tool;
 use crate::error::ErrorDetails;
 use crate::tool::{Node, Package, Tool, Yarn};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 use serde::{Deserialize, Serialize};
-use volta_fail::{Fallible, VoltaError};
+use volta_fail::{Fallible, ResultExt, VoltaError};
 
 #[derive(Serialize, Deserialize)]
 pub struct RawResolveHook {
     prefix: Option<String>,
     template: Option<String>,
     bin: Option<String>,
+    exec: Option<String>,
+}
+
+/// The JSON object printed by an `exec` hook
+#[derive(Deserialize)]
+pub struct RawExecResponse {
+    url: String,
+    #[serde(default)]
+    headers: HashMap<String, String>,
+    checksum: Option<String>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -22,32 +34,49 @@ pub struct RawPublishHook {
 }
 
 impl RawResolveHook {
-    fn into_hook<H, P, T, B>(self, to_prefix: P, to_template: T, to_bin: B) -> Fallible<H>
+    fn into_hook<H, P, T, B, E>(
+        self,
+        to_prefix: P,
+        to_template: T,
+        to_bin: B,
+        to_exec: E,
+    ) -> Fallible<H>
     where
         P: FnOnce(String) -> H,
         T: FnOnce(String) -> H,
         B: FnOnce(String) -> H,
+        E: FnOnce(String) -> H,
     {
         match self {
             RawResolveHook {
                 prefix: Some(prefix),
                 template: None,
                 bin: None,
+                exec: None,
             } => Ok(to_prefix(prefix)),
             RawResolveHook {
                 prefix: None,
                 template: Some(template),
                 bin: None,
+                exec: None,
             } => Ok(to_template(template)),
             RawResolveHook {
                 prefix: None,
                 template: None,
                 bin: Some(bin),
+                exec: None,
             } => Ok(to_bin(bin)),
             RawResolveHook {
                 prefix: None,
                 template: None,
                 bin: None,
+                exec: Some(exec),
+            } => Ok(to_exec(exec)),
+            RawResolveHook {
+                prefix: None,
+                template: None,
+                bin: None,
+                exec: None,
             } => Err(ErrorDetails::HookNoFieldsSpecified.into()),
             _ => Err(ErrorDetails::HookMultipleFieldsSpecified.into()),
         }
@@ -61,6 +90,10 @@ impl RawResolveHook {
                 bin,
                 base_path: base_dir.to_owned(),
             },
+            |exec| tool::DistroHook::Exec {
+                exec,
+                base_path: base_dir.to_owned(),
+            },
         )
     }
 
@@ -72,10 +105,42 @@ impl RawResolveHook {
                 bin,
                 base_path: base_dir.to_owned(),
             },
+            |exec| tool::MetadataHook::Exec {
+                exec,
+                base_path: base_dir.to_owned(),
+            },
         )
     }
 }
 
+impl RawExecResponse {
+    pub fn from_json(src: &str, command: &str) -> Fallible<Self> {
+        serde_json::de::from_str(src).with_context(|_| ErrorDetails::ParseHookResponseError {
+            command: command.to_string(),
+        })
+    }
+
+    pub fn into_location(self, command: &str) -> Fallible<tool::Location> {
+        let mut headers = HeaderMap::new();
+        for (name, value) in self.headers {
+            let invalid_header = || ErrorDetails::InvalidHookHeader {
+                command: command.to_string(),
+                header: name.clone(),
+            };
+            let header_name =
+                HeaderName::from_bytes(name.as_bytes()).with_context(|_| invalid_header())?;
+            let header_value = HeaderValue::from_str(&value).with_context(|_| invalid_header())?;
+            headers.insert(header_name, header_value);
+        }
+
+        Ok(tool::Location {
+            url: self.url,
+            headers,
+            checksum: self.checksum,
+        })
+    }
+}
+
 impl TryFrom<RawPublishHook> for super::Publish {
     type Error = VoltaError;
 
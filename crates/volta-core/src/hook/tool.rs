@@ -1,15 +1,39 @@
 //! Types representing Volta Tool Hooks.
+//!
+//! Besides a URL `prefix`, a URL `template`, or a `bin` that prints a URL, a hook can be an
+//! `exec` program, which is run with the arguments
+//!
+//! ```text
+//! --tool <tool> [--version <version>] --os <os> --arch <arch>
+//! ```
+//!
+//! appended to its command line (the version is only passed to `distro` hooks), and prints a
+//! JSON object describing where to download from:
+//!
+//! ```json
+//! {
+//!   "url": "https://mirror.example.com/node-v12.4.0-linux-x64.tar.gz",
+//!   "headers": { "Authorization": "Bearer abc123" },
+//!   "checksum": "2e2d3b8b8a1e1ce3ab0b0c4f0a3e7f0d9e6f1a2b"
+//! }
+//! ```
+//!
+//! The `headers` are sent with the request, and for `packages` hooks also with any package
+//! tarball downloads from the same origin. The optional `checksum` is the SHA-1 hash that a
+//! downloaded distro must match.
 
 use std::ffi::OsString;
 use std::path::{Path, PathBuf};
 use std::process::Stdio;
 
+use super::serial::RawExecResponse;
 use crate::command::create_command;
 use crate::error::ErrorDetails;
 use crate::path::{ARCH, OS};
 use cmdline_words_parser::StrExt;
 use lazy_static::lazy_static;
 use log::debug;
+use reqwest::header::HeaderMap;
 use semver::Version;
 use volta_fail::{throw, Fallible, ResultExt};
 
@@ -22,26 +46,60 @@ lazy_static! {
     static ref REL_PATH_PARENT: String = format!("..{}", std::path::MAIN_SEPARATOR);
 }
 
+/// A URL resolved by a hook, with any additional details needed to download from it
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Location {
+    pub url: String,
+    /// HTTP headers to send with the request, e.g. for authentication
+    pub headers: HeaderMap,
+    /// The SHA-1 checksum that the downloaded file must match, if any
+    pub checksum: Option<String>,
+}
+
+impl Location {
+    /// Sends a GET request to the location, including its headers
+    pub fn get(&self) -> reqwest::Result<reqwest::Response> {
+        reqwest::Client::new()
+            .get(&self.url)
+            .headers(self.headers.clone())
+            .send()
+    }
+}
+
+impl From<String> for Location {
+    fn from(url: String) -> Self {
+        Location {
+            url,
+            ..Location::default()
+        }
+    }
+}
+
 /// A hook for resolving the distro URL for a given tool version
 #[derive(PartialEq, Debug)]
 pub enum DistroHook {
     Prefix(String),
     Template(String),
     Bin { bin: String, base_path: PathBuf },
+    Exec { exec: String, base_path: PathBuf },
 }
 
 impl DistroHook {
     /// Performs resolution of the distro URL based on the given
-    /// version and file name
-    pub fn resolve(&self, version: &Version, filename: &str) -> Fallible<String> {
+    /// tool, version, and file name
+    pub fn resolve(&self, tool: &str, version: &Version, filename: &str) -> Fallible<Location> {
         match &self {
-            DistroHook::Prefix(prefix) => Ok(format!("{}{}", prefix, filename)),
+            DistroHook::Prefix(prefix) => Ok(format!("{}{}", prefix, filename).into()),
             DistroHook::Template(template) => Ok(template
                 .replace(ARCH_TEMPLATE, ARCH)
                 .replace(OS_TEMPLATE, OS)
-                .replace(VERSION_TEMPLATE, &version.to_string())),
+                .replace(VERSION_TEMPLATE, &version.to_string())
+                .into()),
             DistroHook::Bin { bin, base_path } => {
-                execute_binary(bin, base_path, Some(version.to_string()))
+                execute_binary(bin, base_path, vec![version.to_string()]).map(Location::from)
+            }
+            DistroHook::Exec { exec, base_path } => {
+                execute_exec(exec, base_path, tool, Some(version))
             }
         }
     }
@@ -53,23 +111,49 @@ pub enum MetadataHook {
     Prefix(String),
     Template(String),
     Bin { bin: String, base_path: PathBuf },
+    Exec { exec: String, base_path: PathBuf },
 }
 
 impl MetadataHook {
-    /// Performs resolution of the metadata URL based on the given default file name
-    pub fn resolve(&self, filename: &str) -> Fallible<String> {
+    /// Performs resolution of the metadata URL based on the given tool and default file name
+    pub fn resolve(&self, tool: &str, filename: &str) -> Fallible<Location> {
         match &self {
-            MetadataHook::Prefix(prefix) => Ok(format!("{}{}", prefix, filename)),
+            MetadataHook::Prefix(prefix) => Ok(format!("{}{}", prefix, filename).into()),
             MetadataHook::Template(template) => Ok(template
                 .replace(ARCH_TEMPLATE, ARCH)
-                .replace(OS_TEMPLATE, OS)),
-            MetadataHook::Bin { bin, base_path } => execute_binary(bin, base_path, None),
+                .replace(OS_TEMPLATE, OS)
+                .into()),
+            MetadataHook::Bin { bin, base_path } => {
+                execute_binary(bin, base_path, Vec::new()).map(Location::from)
+            }
+            MetadataHook::Exec { exec, base_path } => execute_exec(exec, base_path, tool, None),
         }
     }
 }
 
+/// Execute an `exec` hook and parse the JSON object it prints into a Location
+fn execute_exec(
+    exec: &str,
+    base_path: &Path,
+    tool: &str,
+    version: Option<&Version>,
+) -> Fallible<Location> {
+    let mut args = vec!["--tool".to_string(), tool.to_string()];
+    if let Some(version) = version {
+        args.push("--version".to_string());
+        args.push(version.to_string());
+    }
+    args.push("--os".to_string());
+    args.push(OS.to_string());
+    args.push("--arch".to_string());
+    args.push(ARCH.to_string());
+
+    let output = execute_binary(exec, base_path, args)?;
+    RawExecResponse::from_json(&output, exec.trim())?.into_location(exec.trim())
+}
+
 /// Execute a shell command and return the trimmed stdout from that command
-fn execute_binary(bin: &str, base_path: &Path, extra_arg: Option<String>) -> Fallible<String> {
+fn execute_binary(bin: &str, base_path: &Path, extra_args: Vec<String>) -> Fallible<String> {
     let mut trimmed = bin.trim().to_string();
     let mut words = trimmed.parse_cmdline_words();
     let cmd = match words.next() {
@@ -91,9 +175,7 @@ fn execute_binary(bin: &str, base_path: &Path, extra_arg: Option<String>) -> Fal
     };
 
     let mut args: Vec<OsString> = words.map(OsString::from).collect();
-    if let Some(arg) = extra_arg {
-        args.push(OsString::from(arg));
-    }
+    args.extend(extra_args.into_iter().map(OsString::from));
 
     let mut command = create_command(cmd);
     command
@@ -129,6 +211,8 @@ pub mod tests {
     use super::{DistroHook, MetadataHook};
     use crate::path::{ARCH, OS};
     use semver::Version;
+    #[cfg(unix)]
+    use std::{fs, os::unix::fs::PermissionsExt};
 
     #[test]
     fn test_distro_prefix_resolve() {
@@ -138,8 +222,9 @@ pub mod tests {
         let version = Version::new(1, 0, 0);
 
         assert_eq!(
-            hook.resolve(&version, filename)
-                .expect("Could not resolve URL"),
+            hook.resolve("node", &version, filename)
+                .expect("Could not resolve URL")
+                .url,
             format!("{}{}", prefix, filename)
         );
     }
@@ -158,8 +243,9 @@ pub mod tests {
         );
 
         assert_eq!(
-            hook.resolve(&version, "node.tar.gz")
-                .expect("Could not resolve URL"),
+            hook.resolve("node", &version, "node.tar.gz")
+                .expect("Could not resolve URL")
+                .url,
             expected
         );
     }
@@ -171,7 +257,9 @@ pub mod tests {
         let hook = MetadataHook::Prefix(prefix.to_string());
 
         assert_eq!(
-            hook.resolve(filename).expect("Could not resolve URL"),
+            hook.resolve("node", filename)
+                .expect("Could not resolve URL")
+                .url,
             format!("{}{}", prefix, filename)
         );
     }
@@ -183,8 +271,40 @@ pub mod tests {
         let expected = format!("http://localhost/node/{}/{}/index.json", OS, ARCH);
 
         assert_eq!(
-            hook.resolve("index.json").expect("Could not resolve URL"),
+            hook.resolve("node", "index.json")
+                .expect("Could not resolve URL")
+                .url,
             expected
         );
     }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_distro_exec_resolve() {
+        let dir = tempfile::tempdir().expect("Could not create temp dir");
+        let script = dir.path().join("hook.sh");
+        fs::write(
+            &script,
+            r#"#!/bin/sh
+echo "{\"url\": \"http://localhost/$2/$4/$6/$8.tar.gz\", \"headers\": {\"Authorization\": \"Bearer token\"}, \"checksum\": \"abc123\"}"
+"#,
+        )
+        .unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let hook = DistroHook::Exec {
+            exec: "./hook.sh".to_string(),
+            base_path: dir.path().to_path_buf(),
+        };
+        let location = hook
+            .resolve("node", &Version::new(1, 0, 0), "node.tar.gz")
+            .expect("Could not resolve URL");
+
+        assert_eq!(
+            location.url,
+            format!("http://localhost/node/1.0.0/{}/{}.tar.gz", OS, ARCH)
+        );
+        assert_eq!(location.headers["authorization"], "Bearer token");
+        assert_eq!(location.checksum, Some("abc123".to_string()));
+    }
 }
@@ -2,6 +2,7 @@
 //! of available tool versions.
 
 mod node;
+mod npm;
 mod package;
 mod yarn;
 
@@ -43,6 +44,7 @@ impl LazyInventory {
 /// The inventory of locally available tool versions.
 pub struct Inventory {
     pub node: node::Collection,
+    pub npm: npm::Collection,
     pub yarn: yarn::Collection,
     pub packages: package::Collection,
 }
@@ -52,6 +54,7 @@ impl Inventory {
     fn current() -> Fallible<Inventory> {
         Ok(Inventory {
             node: node::Collection::load()?,
+            npm: npm::Collection::load()?,
             yarn: yarn::Collection::load()?,
             packages: package::Collection::load()?,
         })
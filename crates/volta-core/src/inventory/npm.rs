@@ -0,0 +1,41 @@
+use std::collections::BTreeSet;
+
+use regex::Regex;
+use semver::Version;
+
+use volta_fail::Fallible;
+
+use super::versions_matching;
+use crate::path;
+
+// Convenience for access as `npm::Collection`
+pub use NpmCollection as Collection;
+
+pub struct NpmCollection {
+    pub versions: BTreeSet<Version>,
+}
+
+impl NpmCollection {
+    pub(crate) fn load() -> Fallible<Self> {
+        let re = Regex::new(
+            r"(?x)
+            npm
+            -
+            (?P<version>\d+\.\d+\.\d+) # npm version
+            \.tgz
+            ",
+        )
+        .unwrap();
+
+        // The npm inventory directory was added after the initial layout, so it may not exist
+        // in a Volta directory created by an earlier version.
+        let dir = path::npm_inventory_dir()?;
+        let versions = if dir.exists() {
+            versions_matching(&dir, &re)?
+        } else {
+            BTreeSet::new()
+        };
+
+        Ok(Collection { versions })
+    }
+}
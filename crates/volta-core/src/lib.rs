@@ -4,6 +4,8 @@
 // https://doc.rust-lang.org/nightly/unstable-book/language-features/doc-cfg.html
 #![cfg_attr(feature = "cross-platform-docs", feature(doc_cfg))]
 
+pub mod alias;
+pub mod cache;
 mod command;
 pub mod env;
 pub mod error;
@@ -13,8 +15,11 @@ mod hook;
 pub mod inventory;
 pub mod log;
 pub mod manifest;
+#[cfg(target_os = "linux")]
+pub mod migrate;
 pub mod monitor;
 pub mod path;
+pub mod permissions;
 pub mod platform;
 pub mod project;
 pub mod run;
@@ -22,7 +27,9 @@ pub mod session;
 pub mod shell;
 pub mod shim;
 pub mod signal;
+pub mod snapshot;
 pub mod style;
+pub mod timing;
 pub mod tool;
 pub mod toolchain;
 pub mod version;
@@ -1,12 +1,10 @@
 //! This module provides a custom Logger implementation for use with the `log` crate
 use atty::Stream;
-use console::style;
 use log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
 use std::env;
 use std::fmt::Display;
-use textwrap::{NoHyphenation, Wrapper};
 
-use crate::style::text_width;
+use crate::style::{display_error, display_warning};
 
 const ERROR_PREFIX: &'static str = "error:";
 const WARNING_PREFIX: &'static str = "warning:";
@@ -14,7 +12,6 @@ const SHIM_ERROR_PREFIX: &'static str = "Volta error:";
 const SHIM_WARNING_PREFIX: &'static str = "Volta warning:";
 const VOLTA_LOGLEVEL: &'static str = "VOLTA_LOGLEVEL";
 const ALLOWED_PREFIX: &'static str = "volta";
-const WRAP_INDENT: &'static str = "    ";
 
 /// Represents the context from which the logger was created
 pub enum LogContext {
@@ -87,7 +84,7 @@ impl Logger {
             LogContext::Shim => SHIM_ERROR_PREFIX,
         };
 
-        eprintln!("{} {}", style(prefix).red().bold(), message);
+        eprintln!("{}", display_error(prefix, message));
     }
 
     fn log_warning<D>(&self, message: &D)
@@ -99,30 +96,7 @@ impl Logger {
             LogContext::Shim => SHIM_WARNING_PREFIX,
         };
 
-        eprintln!(
-            "{}{}",
-            style(prefix).yellow().bold(),
-            wrap_content(prefix, message)
-        );
-    }
-}
-
-/// Wraps the supplied content to the terminal width, if we are in a terminal.
-/// If not, returns the content as a String
-///
-/// Note: Uses the supplied prefix to calculate the terminal width, but then removes
-/// it so that it can be styled (style characters are counted against the wrapped width)
-fn wrap_content<D>(prefix: &str, content: &D) -> String
-where
-    D: Display,
-{
-    match text_width() {
-        Some(width) => Wrapper::with_splitter(width, NoHyphenation)
-            .subsequent_indent(WRAP_INDENT)
-            .break_words(false)
-            .fill(&format!("{} {}", prefix, content))
-            .replace(prefix, ""),
-        None => format!(" {}", content),
+        eprintln!("{}", display_warning(prefix, message));
     }
 }
 
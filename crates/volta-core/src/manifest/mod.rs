@@ -8,7 +8,7 @@ use std::rc::Rc;
 use crate::error::ErrorDetails;
 use crate::platform::PlatformSpec;
 use detect_indent;
-use semver::Version;
+use semver::{Version, VersionReq};
 use serde::Serialize;
 use serde_json;
 use volta_fail::{Fallible, ResultExt};
@@ -19,6 +19,10 @@ pub(crate) mod serial;
 pub struct Manifest {
     /// The platform image specified by the `volta` section.
     pub platform: Option<Rc<PlatformSpec>>,
+    /// The npm requirement from the `volta` section, if it is a range rather than an exact
+    /// version. The range is resolved when the platform is checked out, see
+    /// `Session::resolve_project_npm`.
+    pub npm_requirement: Option<VersionReq>,
     /// The `dependencies` section.
     pub dependencies: HashMap<String, String>,
     /// The `devDependencies` section.
@@ -29,11 +33,14 @@ impl Manifest {
     /// Loads and parses a Node manifest for the project rooted at the specified path.
     pub fn for_dir(project_root: &Path) -> Fallible<Manifest> {
         let package_file = project_root.join("package.json");
-        let file = File::open(&package_file).with_context(|_| ErrorDetails::PackageReadError {
-            file: package_file.to_path_buf(),
-        })?;
+        // Reading the file up front is much faster than deserializing from the unbuffered
+        // `File`, and this runs on every shim invocation inside a project
+        let contents =
+            read_to_string(&package_file).with_context(|_| ErrorDetails::PackageReadError {
+                file: package_file.to_path_buf(),
+            })?;
 
-        let serial: serial::Manifest = serde_json::de::from_reader(file).with_context(|_| {
+        let serial: serial::Manifest = serde_json::de::from_str(&contents).with_context(|_| {
             ErrorDetails::PackageParseError {
                 file: package_file.to_path_buf(),
             }
@@ -65,6 +72,16 @@ impl Manifest {
         self.platform().map(|t| t.node_runtime.to_string())
     }
 
+    /// Returns the pinned version or range of npm as a String, if any.
+    pub fn npm_str(&self) -> Option<String> {
+        match self.npm_requirement {
+            Some(ref requirement) => Some(requirement.to_string()),
+            None => self
+                .platform()
+                .and_then(|t| t.npm.as_ref().map(|npm| npm.to_string())),
+        }
+    }
+
     /// Returns the pinned verison of Yarn as a Version, if any.
     pub fn yarn(&self) -> Option<Version> {
         self.platform().map(|t| t.yarn.clone()).unwrap_or(None)
@@ -124,11 +141,12 @@ pub struct BinManifest {
 impl BinManifest {
     pub fn for_dir(project_root: &Path) -> Fallible<Self> {
         let package_file = project_root.join("package.json");
-        let file = File::open(&package_file).with_context(|_| ErrorDetails::PackageReadError {
-            file: package_file.to_path_buf(),
-        })?;
+        let contents =
+            read_to_string(&package_file).with_context(|_| ErrorDetails::PackageReadError {
+                file: package_file.to_path_buf(),
+            })?;
 
-        serde_json::de::from_reader::<File, serial::RawBinManifest>(file)
+        serde_json::de::from_str::<serial::RawBinManifest>(&contents)
             .with_context(|_| ErrorDetails::PackageParseError {
                 file: package_file.to_path_buf(),
             })
@@ -7,6 +7,7 @@ use std::path::Path;
 use std::rc::Rc;
 
 use log::warn;
+use semver::{Version, VersionReq};
 use serde;
 use serde::de::{Deserialize, Deserializer, Error, MapAccess, Visitor};
 use serde_json::value::Value;
@@ -76,6 +77,9 @@ pub struct RawBinManifest {
     pub engines: Option<Engines>,
 }
 
+/// The value of the `npm` key which selects the npm bundled with the pinned Node version.
+const BUNDLED_NPM: &'static str = "bundled";
+
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct ToolchainSpec {
     pub node: String,
@@ -117,8 +121,14 @@ impl Engines {
 
 impl Manifest {
     pub fn into_manifest(self, package_path: &Path) -> Fallible<manifest::Manifest> {
+        let npm_requirement = match self.volta.as_ref().or(self.toolchain.as_ref()) {
+            Some(toolchain) => toolchain.npm_requirement()?,
+            None => None,
+        };
+
         Ok(manifest::Manifest {
             platform: self.to_platform(package_path)?.map(Rc::new),
+            npm_requirement,
             dependencies: self.dependencies,
             dev_dependencies: self.dev_dependencies,
         })
@@ -152,11 +162,7 @@ impl Manifest {
         if let Some(toolchain) = &toolchain {
             return Ok(Some(platform::PlatformSpec {
                 node_runtime: VersionSpec::parse_version(&toolchain.node)?,
-                npm: if let Some(npm) = &toolchain.npm {
-                    Some(VersionSpec::parse_version(&npm)?)
-                } else {
-                    None
-                },
+                npm: toolchain.npm_version()?,
                 yarn: if let Some(yarn) = &toolchain.yarn {
                     Some(VersionSpec::parse_version(&yarn)?)
                 } else {
@@ -190,7 +196,43 @@ impl From<RawBinManifest> for super::BinManifest {
     }
 }
 
+/// The parsed `npm` key of a toolchain spec.
+enum NpmSpec {
+    /// Use the npm bundled with the pinned Node version.
+    Bundled,
+    /// Use exactly this version of npm.
+    Exact(Version),
+    /// Use the newest npm matching this requirement.
+    Range(VersionReq),
+}
+
 impl ToolchainSpec {
+    fn npm_spec(&self) -> Fallible<NpmSpec> {
+        match self.npm.as_ref().map(String::as_str) {
+            None | Some(BUNDLED_NPM) => Ok(NpmSpec::Bundled),
+            Some(npm) => match VersionSpec::parse_version(npm) {
+                Ok(version) => Ok(NpmSpec::Exact(version)),
+                Err(_) => VersionSpec::parse_requirements(npm).map(NpmSpec::Range),
+            },
+        }
+    }
+
+    /// Returns the exact version of npm specified, if any.
+    fn npm_version(&self) -> Fallible<Option<Version>> {
+        match self.npm_spec()? {
+            NpmSpec::Exact(version) => Ok(Some(version)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Returns the requirement for npm, if a range was specified instead of an exact version.
+    fn npm_requirement(&self) -> Fallible<Option<VersionReq>> {
+        match self.npm_spec()? {
+            NpmSpec::Range(requirement) => Ok(Some(requirement)),
+            _ => Ok(None),
+        }
+    }
+
     pub fn new(
         node_version: String,
         npm_version: Option<String>,
@@ -275,7 +317,9 @@ where
 #[cfg(test)]
 pub mod tests {
 
-    use super::{BinMap, Engines, Manifest, RawBinManifest};
+    use super::{BinMap, Engines, Manifest, RawBinManifest, ToolchainSpec};
+    use crate::version::VersionSpec;
+    use semver::Version;
     use serde_json;
     use std::collections::HashMap;
 
@@ -482,6 +526,34 @@ pub mod tests {
         assert_eq!(toolchain_node_and_yarn.yarn.unwrap(), "1.2.1");
     }
 
+    #[test]
+    fn test_toolchain_npm() {
+        let bundled = ToolchainSpec::new("10.15.3".into(), Some("bundled".into()), None);
+        assert_eq!(bundled.npm_version().unwrap(), None);
+        assert_eq!(bundled.npm_requirement().unwrap(), None);
+
+        let missing = ToolchainSpec::new("10.15.3".into(), None, None);
+        assert_eq!(missing.npm_version().unwrap(), None);
+        assert_eq!(missing.npm_requirement().unwrap(), None);
+
+        let exact = ToolchainSpec::new("10.15.3".into(), Some("6.9.0".into()), None);
+        assert_eq!(
+            exact.npm_version().unwrap(),
+            Some(Version::parse("6.9.0").unwrap())
+        );
+        assert_eq!(exact.npm_requirement().unwrap(), None);
+
+        let range = ToolchainSpec::new("10.15.3".into(), Some("^6.9".into()), None);
+        assert_eq!(range.npm_version().unwrap(), None);
+        assert_eq!(
+            range.npm_requirement().unwrap(),
+            Some(VersionSpec::parse_requirements("^6.9").unwrap())
+        );
+
+        let invalid = ToolchainSpec::new("10.15.3".into(), Some("newest".into()), None);
+        assert!(invalid.npm_requirement().is_err());
+    }
+
     #[test]
     fn test_package_bin() {
         let package_no_bin = r#"{
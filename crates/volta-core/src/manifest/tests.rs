@@ -127,3 +127,12 @@ fn handles_dep_with_no_bin() {
     let expected_bin = HashMap::new();
     assert_eq!(bin, expected_bin);
 }
+
+#[test]
+fn bundled_npm_needs_no_resolution() {
+    let project_path = fixture_path("bundled_npm");
+    let manifest = Manifest::for_dir(&project_path).expect("Could not get manifest");
+    assert_eq!(manifest.npm_requirement, None);
+    assert_eq!(manifest.platform().unwrap().npm, None);
+    assert_eq!(manifest.npm_str(), None);
+}
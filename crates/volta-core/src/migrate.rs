@@ -0,0 +1,148 @@
+//! Provides migrations of Volta's files from one on-disk layout to another.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use log::{debug, info, warn};
+
+use crate::error::ErrorDetails;
+use crate::fs::read_dir_eager;
+use crate::path::{self, default_volta_home, xdg::XdgDirs};
+use crate::shim;
+use volta_fail::{Fallible, ResultExt};
+
+/// Moves an existing `~/.volta` directory into the XDG layout. This only happens the first
+/// time the XDG layout is used, i.e. before its data directory has been created.
+pub fn migrate_home_to_xdg(dirs: &XdgDirs) -> Fallible<()> {
+    let home = default_volta_home()?;
+    if dirs.data.exists() || !home.is_dir() {
+        return Ok(());
+    }
+
+    info!("Moving {} into the XDG base directories", home.display());
+    move_home(&home, dirs)?;
+    relink_shims()?;
+
+    // These are created up front in a new install, so later writes expect them to exist
+    ensure_dir(&path::node_cache_dir()?)?;
+    ensure_dir(&path::log_dir()?)?;
+
+    warn!(
+        "Volta's shims have moved to {}
+Please update the PATH in your shell profile to use the new location.",
+        path::shim_dir()?.display()
+    );
+
+    Ok(())
+}
+
+/// Moves the contents of a single directory layout into the XDG directories: the caches
+/// and logs to the cache directory, the hooks to the config directory, and everything
+/// else to the data directory.
+fn move_home(home: &Path, dirs: &XdgDirs) -> Fallible<()> {
+    // The caches and logs can be recreated, so they are discarded if they can't be moved
+    move_or_discard(&home.join("cache"), &dirs.cache);
+    move_or_discard(&home.join("log"), &dirs.cache.join("log"));
+
+    let hooks = home.join("hooks.json");
+    if hooks.exists() {
+        ensure_dir(&dirs.config)?;
+        rename(&hooks, &dirs.config.join("hooks.json"))?;
+    }
+
+    if let Some(parent) = dirs.data.parent() {
+        ensure_dir(parent)?;
+    }
+    rename(home, &dirs.data)
+}
+
+/// Recreates the shims, which are symlinks to the shim executable that has just moved.
+fn relink_shims() -> Fallible<()> {
+    let shim_dir = path::shim_dir()?;
+    let entries = read_dir_eager(&shim_dir).with_context(|_| ErrorDetails::ReadShimDirError {
+        dir: shim_dir.clone(),
+    })?;
+
+    for (entry, metadata) in entries {
+        if !metadata.file_type().is_symlink() {
+            continue;
+        }
+
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        fs::remove_file(entry.path()).with_context(|_| ErrorDetails::ShimRemoveError {
+            name: name.to_string(),
+        })?;
+        shim::create(&name)?;
+    }
+
+    Ok(())
+}
+
+fn move_or_discard(from: &Path, to: &Path) {
+    if !from.exists() {
+        return;
+    }
+
+    let moved = fs::create_dir_all(to).and_then(|_| {
+        for (entry, _) in read_dir_eager(from)? {
+            fs::rename(entry.path(), to.join(entry.file_name()))?;
+        }
+        fs::remove_dir(from)
+    });
+
+    if let Err(error) = moved {
+        debug!("Discarding {}: {}", from.display(), error);
+        let _ = fs::remove_dir_all(from);
+    }
+}
+
+fn ensure_dir(dir: &Path) -> Fallible<()> {
+    fs::create_dir_all(dir).with_context(|_| ErrorDetails::CreateDirError {
+        dir: dir.to_path_buf(),
+    })
+}
+
+fn rename(from: &Path, to: &Path) -> Fallible<()> {
+    fs::rename(from, to).with_context(|_: &io::Error| ErrorDetails::LayoutMigrationError {
+        from: from.to_path_buf(),
+        to: to.to_path_buf(),
+    })
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_move_home() {
+        let root = tempdir().expect("Could not create temp dir");
+        let home = root.path().join(".volta");
+        let dirs = XdgDirs {
+            data: root.path().join(".local").join("share").join("volta"),
+            cache: root.path().join(".cache").join("volta"),
+            config: root.path().join(".config").join("volta"),
+        };
+
+        fs::create_dir_all(home.join("cache").join("node")).unwrap();
+        fs::create_dir_all(home.join("log")).unwrap();
+        fs::create_dir_all(home.join("tools").join("inventory")).unwrap();
+        fs::create_dir_all(home.join("bin")).unwrap();
+        fs::write(home.join("cache").join("node").join("index.json"), "[]").unwrap();
+        fs::write(home.join("log").join("volta-error.log"), "").unwrap();
+        fs::write(home.join("hooks.json"), "{}").unwrap();
+
+        move_home(&home, &dirs).unwrap();
+
+        assert!(!home.exists());
+        assert!(dirs.cache.join("node").join("index.json").is_file());
+        assert!(dirs.cache.join("log").join("volta-error.log").is_file());
+        assert!(dirs.config.join("hooks.json").is_file());
+        assert!(dirs.data.join("tools").join("inventory").is_dir());
+        assert!(dirs.data.join("bin").is_dir());
+        assert!(!dirs.data.join("cache").exists());
+        assert!(!dirs.data.join("hooks.json").exists());
+    }
+}
@@ -7,7 +7,12 @@ use std::fs;
 use std::path::{Path, PathBuf};
 
 use crate::error::ErrorDetails;
+#[cfg(target_os = "linux")]
+use crate::migrate;
 use crate::shim;
+use lazy_static::lazy_static;
+#[cfg(target_os = "linux")]
+use log::warn;
 use volta_fail::{Fallible, ResultExt};
 
 cfg_if::cfg_if! {
@@ -32,34 +37,149 @@ cfg_if::cfg_if! {
     }
 }
 
-pub fn ensure_volta_dirs_exist() -> Fallible<()> {
-    // Assume that if volta_home() exists, then the directory structure has been initialized
-    if !volta_home()?.exists() {
-        ensure_dir_exists(node_cache_dir()?)?;
-        ensure_dir_exists(shim_dir()?)?;
-        ensure_dir_exists(node_inventory_dir()?)?;
-        ensure_dir_exists(package_inventory_dir()?)?;
-        ensure_dir_exists(yarn_inventory_dir()?)?;
-        ensure_dir_exists(node_image_root_dir()?)?;
-        ensure_dir_exists(yarn_image_root_dir()?)?;
-        ensure_dir_exists(user_toolchain_dir()?)?;
-        ensure_dir_exists(tmp_dir()?)?;
-        ensure_dir_exists(log_dir()?)?;
-        // also ensure the basic shims exist
-        // this is only for unix until the update process is refactored
-        // (windows stores the location in the Registry, which is not available for the tests)
-        #[cfg(unix)]
+#[cfg(target_os = "linux")]
+pub mod xdg;
+
+/// The arrangement of Volta's files on disk.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Layout {
+    /// All files are in a single directory, `VOLTA_HOME` or the platform default.
+    Home(PathBuf),
+    /// Files are split across the XDG base directories (opt-in, Linux only).
+    #[cfg(target_os = "linux")]
+    Xdg(xdg::XdgDirs),
+}
+
+/// The version of the directory layout. Its marker file is written once `init_layout` has
+/// created the whole layout, and a change to the layout needs a new version.
+const LAYOUT_VERSION: u32 = 1;
+
+lazy_static! {
+    /// Every path depends on the layout, so it is only determined once per process. If that
+    /// fails, `layout` determines it again to report the error.
+    static ref LAYOUT: Option<Layout> = current_layout().ok();
+}
+
+/// Returns the layout in use. An explicit `VOLTA_HOME` always selects the single directory
+/// layout, even if the XDG layout was selected, since the installer exports `VOLTA_HOME` in the
+/// shell profile. A warning is shown in that case, as the profile needs to be updated.
+pub fn layout() -> Fallible<Layout> {
+    match *LAYOUT {
+        Some(ref layout) => Ok(layout.clone()),
+        None => current_layout(),
+    }
+}
+
+fn current_layout() -> Fallible<Layout> {
+    if let Some(home) = env::var_os("VOLTA_HOME") {
+        #[cfg(target_os = "linux")]
         {
-            ensure_shim_exists("node")?;
-            ensure_shim_exists("yarn")?;
-            ensure_shim_exists("npm")?;
-            ensure_shim_exists("npx")?;
+            if xdg::is_selected().unwrap_or(false) {
+                warn!(
+                    "The XDG layout is selected, but VOLTA_HOME is set, so Volta uses {}
+Remove VOLTA_HOME from your shell profile to use the XDG base directories.",
+                    Path::new(&home).display()
+                );
+            }
         }
+        return Ok(Layout::Home(PathBuf::from(home)));
     }
 
-    Ok(())
+    #[cfg(target_os = "linux")]
+    {
+        if xdg::is_selected()? {
+            return Ok(Layout::Xdg(xdg::XdgDirs::current()?));
+        }
+    }
+
+    Ok(Layout::Home(default_volta_home()?))
 }
 
+/// Ensures that the full directory layout exists, creating it on first run. Once the layout
+/// version marker has been written this only checks for it, so every entry point can call
+/// this before doing any work.
+pub fn ensure_layout() -> Fallible<()> {
+    #[cfg(target_os = "linux")]
+    {
+        if let Layout::Xdg(dirs) = layout()? {
+            dirs.save_selection()?;
+            migrate::migrate_home_to_xdg(&dirs)?;
+        }
+    }
+
+    if layout_version_file()?.exists() {
+        return Ok(());
+    }
+
+    init_layout()
+}
+
+/// Creates every directory in the layout, along with the built-in shims, and then writes the
+/// layout version marker. Existing files are left alone, so this can be run again to repair
+/// a partial layout.
+pub fn init_layout() -> Fallible<()> {
+    for dir in layout_dirs()? {
+        ensure_dir_exists(dir)?;
+    }
+    // also ensure the basic shims exist
+    // this is only for unix until the update process is refactored
+    // (windows stores the location in the Registry, which is not available for the tests)
+    #[cfg(unix)]
+    {
+        ensure_shim_exists("node")?;
+        ensure_shim_exists("yarn")?;
+        ensure_shim_exists("npm")?;
+        ensure_shim_exists("npx")?;
+    }
+
+    // The marker is written last, so an interrupted run is completed by the next one
+    let marker = layout_version_file()?;
+    fs::write(&marker, "").with_context(|_| ErrorDetails::WriteLayoutFileError { file: marker })
+}
+
+/// The directories created by `init_layout`.
+pub fn layout_dirs() -> Fallible<Vec<PathBuf>> {
+    Ok(vec![
+        node_cache_dir()?,
+        shim_dir()?,
+        node_inventory_dir()?,
+        package_inventory_dir()?,
+        yarn_inventory_dir()?,
+        npm_inventory_dir()?,
+        node_image_root_dir()?,
+        yarn_image_root_dir()?,
+        npm_image_root_dir()?,
+        package_image_root_dir()?,
+        user_bin_dir()?,
+        user_package_dir()?,
+        npm_prefix_dir()?,
+        npm_cache_dir()?,
+        tmp_dir()?,
+        log_dir()?,
+    ])
+}
+
+/// The top-level directories that contain all of Volta's files.
+pub fn layout_roots() -> Fallible<Vec<PathBuf>> {
+    match layout()? {
+        Layout::Home(home) => Ok(vec![home]),
+        #[cfg(target_os = "linux")]
+        Layout::Xdg(dirs) => Ok(vec![dirs.data, dirs.cache, dirs.config]),
+    }
+}
+
+#[cfg(unix)]
+fn ensure_dir_exists(path: PathBuf) -> Fallible<()> {
+    use std::os::unix::fs::DirBuilderExt;
+
+    fs::DirBuilder::new()
+        .recursive(true)
+        .mode(0o755)
+        .create(&path)
+        .with_context(|_| ErrorDetails::CreateDirError { dir: path })
+}
+
+#[cfg(windows)]
 fn ensure_dir_exists(path: PathBuf) -> Fallible<()> {
     fs::create_dir_all(&path).with_context(|_| ErrorDetails::CreateDirError { dir: path })
 }
@@ -69,15 +189,23 @@ fn ensure_shim_exists(shim_name: &str) -> Fallible<shim::ShimResult> {
 }
 
 pub fn volta_home() -> Fallible<PathBuf> {
-    if let Some(home) = env::var_os("VOLTA_HOME") {
-        Ok(Path::new(&home).to_path_buf())
-    } else {
-        default_volta_home()
+    match layout()? {
+        Layout::Home(home) => Ok(home),
+        #[cfg(target_os = "linux")]
+        Layout::Xdg(dirs) => Ok(dirs.data),
     }
 }
 
 pub fn cache_dir() -> Fallible<PathBuf> {
-    Ok(volta_home()?.join("cache"))
+    match layout()? {
+        Layout::Home(home) => Ok(home.join("cache")),
+        #[cfg(target_os = "linux")]
+        Layout::Xdg(dirs) => Ok(dirs.cache),
+    }
+}
+
+pub fn layout_version_file() -> Fallible<PathBuf> {
+    Ok(volta_home()?.join(format!("layout.v{}", LAYOUT_VERSION)))
 }
 
 pub fn tmp_dir() -> Fallible<PathBuf> {
@@ -85,7 +213,11 @@ pub fn tmp_dir() -> Fallible<PathBuf> {
 }
 
 pub fn log_dir() -> Fallible<PathBuf> {
-    Ok(volta_home()?.join("log"))
+    match layout()? {
+        Layout::Home(home) => Ok(home.join("log")),
+        #[cfg(target_os = "linux")]
+        Layout::Xdg(dirs) => Ok(dirs.cache.join("log")),
+    }
 }
 
 pub fn node_inventory_dir() -> Fallible<PathBuf> {
@@ -96,6 +228,10 @@ pub fn yarn_inventory_dir() -> Fallible<PathBuf> {
     Ok(inventory_dir()?.join("yarn"))
 }
 
+pub fn npm_inventory_dir() -> Fallible<PathBuf> {
+    Ok(inventory_dir()?.join("npm"))
+}
+
 pub fn package_inventory_dir() -> Fallible<PathBuf> {
     Ok(inventory_dir()?.join("packages"))
 }
@@ -112,6 +248,10 @@ pub fn node_cache_dir() -> Fallible<PathBuf> {
     Ok(cache_dir()?.join("node"))
 }
 
+pub fn npm_cache_dir() -> Fallible<PathBuf> {
+    Ok(cache_dir()?.join("npm"))
+}
+
 pub fn node_index_file() -> Fallible<PathBuf> {
     Ok(node_cache_dir()?.join("index.json"))
 }
@@ -144,6 +284,18 @@ pub fn yarn_image_bin_dir(version: &str) -> Fallible<PathBuf> {
     Ok(yarn_image_dir(version)?.join("bin"))
 }
 
+pub fn npm_image_root_dir() -> Fallible<PathBuf> {
+    Ok(image_dir()?.join("npm"))
+}
+
+pub fn npm_image_dir(version: &str) -> Fallible<PathBuf> {
+    Ok(npm_image_root_dir()?.join(version))
+}
+
+pub fn npm_image_bin_dir(version: &str) -> Fallible<PathBuf> {
+    Ok(npm_image_dir(version)?.join("bin"))
+}
+
 pub fn package_image_root_dir() -> Fallible<PathBuf> {
     Ok(image_dir()?.join("packages"))
 }
@@ -157,7 +309,11 @@ pub fn shim_dir() -> Fallible<PathBuf> {
 }
 
 pub fn user_hooks_file() -> Fallible<PathBuf> {
-    Ok(volta_home()?.join("hooks.json"))
+    match layout()? {
+        Layout::Home(home) => Ok(home.join("hooks.json")),
+        #[cfg(target_os = "linux")]
+        Layout::Xdg(dirs) => Ok(dirs.config.join("hooks.json")),
+    }
 }
 
 pub fn tools_dir() -> Fallible<PathBuf> {
@@ -192,6 +348,10 @@ pub fn user_tool_bin_config(bin_name: &str) -> Fallible<PathBuf> {
     Ok(user_bin_dir()?.join(format!("{}.json", bin_name)))
 }
 
+pub fn npm_prefix_dir() -> Fallible<PathBuf> {
+    Ok(user_toolchain_dir()?.join("npm"))
+}
+
 pub fn node_distro_file_name(version: &str) -> String {
     format!(
         "{}.{}",
@@ -217,6 +377,14 @@ pub fn yarn_archive_root_dir_name(version: &str) -> String {
     format!("yarn-v{}", version)
 }
 
+pub fn npm_distro_file_name(version: &str) -> String {
+    package_distro_file_name("npm", version)
+}
+
+pub fn npm_archive_root_dir_name() -> String {
+    "package".to_string()
+}
+
 pub fn package_distro_file_name(name: &str, version: &str) -> String {
     format!("{}.tgz", package_archive_root_dir_name(name, version))
 }
@@ -295,6 +463,11 @@ pub mod tests {
         assert_eq!(yarn_distro_file_name("1.2.3"), "yarn-v1.2.3.tar.gz");
     }
 
+    #[test]
+    fn test_npm_distro_file_name() {
+        assert_eq!(npm_distro_file_name("6.9.0"), "npm-6.9.0.tgz");
+    }
+
     #[test]
     fn yarn_node_archive_root_dir() {
         assert_eq!(
@@ -11,7 +11,7 @@ use cfg_if::cfg_if;
 use dirs;
 use volta_fail::Fallible;
 
-use super::{node_archive_root_dir_name, node_image_dir, shim_dir};
+use super::{node_archive_root_dir_name, node_image_dir, npm_prefix_dir, shim_dir};
 
 // These are taken from: https://nodejs.org/dist/index.json and are used
 // by `path::archive_root_dir` to determine the root directory of the
@@ -35,6 +35,7 @@ cfg_if! {
 //             node\                                       node_cache_dir
 //                 index.json                              node_index_file
 //                 index.json.expires                      node_index_expiry_file
+//             npm\                                        npm_cache_dir
 //         bin\                                            shim_dir
 //             node.exe                                    shim_file("node")
 //             npm.exe
@@ -51,6 +52,8 @@ cfg_if! {
 //                     ember-cli-3.7.1.tgz                 package_distro_file("ember-cli", "3.7.1")
 //                     ember-cli-3.7.1.shasum              package_distro_shasum("ember-cli", "3.7.1")
 //                 yarn\                                   yarn_inventory_dir
+//                 npm\                                    npm_inventory_dir
+//                     npm-6.9.0.tgz                       npm_distro_file_name("6.9.0")
 //             image\                                      image_dir
 //                 node\                                   node_image_root_dir
 //                     10.13.0\
@@ -58,6 +61,9 @@ cfg_if! {
 //                                                         node_image_bin_dir("10.13.0", "6.4.0")
 //                 yarn\                                   yarn_image_root_dir
 //                     1.7.0\                              yarn_image_dir("1.7.0")
+//                 npm\                                    npm_image_root_dir
+//                     6.9.0\                              npm_image_dir("6.9.0")
+//                         bin\                            npm_image_bin_dir("6.9.0")
 //                 packages\                               package_image_root_dir
 //                     ember-cli\
 //                         3.7.1\                          package_image_dir("ember-cli", "3.7.1")
@@ -66,8 +72,10 @@ cfg_if! {
 //                     tsc.json                            user_tool_bin_config("tsc")
 //                 packages\                               user_package_dir
 //                     ember-cli.json                      user_package_config_file("ember-cli")
+//                 npm\                                    npm_prefix_dir, npm_prefix_bin_dir
 //                 platform.json                           user_platform_file
 //         hooks.json                                      user_hooks_file
+//         layout.v1                                       layout_version_file
 //
 // C:\Program Files\
 //     Volta\                                             (Path stored in Windows Registry by installer)
@@ -92,11 +100,24 @@ pub fn node_image_bin_dir(node: &str, npm: &str) -> Fallible<PathBuf> {
     node_image_dir(node, npm)
 }
 
+/// The directory where npm links the executables of global packages installed under
+/// `npm_prefix_dir`, which on Windows is the prefix itself.
+pub fn npm_prefix_bin_dir() -> Fallible<PathBuf> {
+    npm_prefix_dir()
+}
+
 pub fn node_archive_npm_package_json_path(version: &str) -> PathBuf {
-    Path::new(&node_archive_root_dir_name(version))
-        .join("node_modules")
-        .join("npm")
-        .join("package.json")
+    node_install_npm_package_json_path(Path::new(&node_archive_root_dir_name(version)))
+}
+
+/// The path of the `node` executable in a Node installation, such as an unpacked distro.
+pub fn node_install_executable(root: &Path) -> PathBuf {
+    root.join("node.exe")
+}
+
+/// The path of the bundled npm's `package.json` in a Node installation.
+pub fn node_install_npm_package_json_path(root: &Path) -> PathBuf {
+    root.join("node_modules").join("npm").join("package.json")
 }
 
 cfg_if::cfg_if! {
@@ -161,3 +182,13 @@ pub fn create_file_symlink(src: PathBuf, dst: PathBuf) -> Result<(), io::Error>
     #[cfg(feature = "cross-platform-docs")]
     unimplemented!()
 }
+
+/// Create a directory symlink. The `dst` path will be a symbolic link pointing to the `src` path.
+pub fn create_dir_symlink(src: PathBuf, dst: PathBuf) -> Result<(), io::Error> {
+    #[cfg(windows)]
+    return windows::fs::symlink_dir(src, dst);
+
+    // Cross-platform docs are built on a Unix machine so we can't use Windows APIs.
+    #[cfg(feature = "cross-platform-docs")]
+    unimplemented!()
+}
@@ -0,0 +1,126 @@
+//! Provides functions for determining the paths of the directories in the opt-in
+//! Linux layout, which follows the XDG Base Directory Specification:
+//! https://specifications.freedesktop.org/basedir-spec/latest/
+//!
+//! The layout is selected by running Volta once with `VOLTA_LAYOUT=xdg`, which moves an
+//! existing `~/.volta` into the new directories. `VOLTA_HOME` takes precedence over the
+//! selection, so it has to be removed from the shell profile that the installer wrote.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use dirs;
+
+use crate::error::ErrorDetails;
+use crate::fs::read_file;
+use volta_fail::{Fallible, ResultExt};
+
+// ~/
+//     .local/share/volta/                                 data_dir, volta_home
+//         bin/                                            shim_dir
+//         tools/                                          tools_dir
+//             ...
+//         tmp/                                            tmp_dir
+//         volta                                           volta_file
+//         shim                                            shim_executable
+//         layout.v1                                       layout_version_file
+//     .cache/volta/                                       cache_dir
+//         node/                                           node_cache_dir
+//             index.json                                  node_index_file
+//             index.json.expires                          node_index_expiry_file
+//         npm/                                            npm_cache_dir
+//         log/                                            log_dir
+//     .config/volta/                                      config_dir
+//         hooks.json                                      user_hooks_file
+//         layout                                          layout_file
+//
+// The `tools` directory is kept with the data rather than the cache so that downloads
+// staged in `tmp` can always be renamed into the inventory without crossing filesystems.
+
+/// The environment variable used to choose a layout on first run.
+const LAYOUT_VAR: &'static str = "VOLTA_LAYOUT";
+
+/// The contents of the layout file which select this layout.
+const XDG_LAYOUT: &'static str = "xdg";
+
+/// The Volta directories within each of the XDG base directories.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XdgDirs {
+    pub data: PathBuf,
+    pub cache: PathBuf,
+    pub config: PathBuf,
+}
+
+impl XdgDirs {
+    /// Determines the directories from the `XDG_DATA_HOME`, `XDG_CACHE_HOME`, and
+    /// `XDG_CONFIG_HOME` environment variables, falling back to the defaults in the spec.
+    pub fn current() -> Fallible<Self> {
+        let data = dirs::data_dir().ok_or(ErrorDetails::NoHomeEnvironmentVar)?;
+        let cache = dirs::cache_dir().ok_or(ErrorDetails::NoHomeEnvironmentVar)?;
+        let config = dirs::config_dir().ok_or(ErrorDetails::NoHomeEnvironmentVar)?;
+
+        Ok(XdgDirs {
+            data: data.join("volta"),
+            cache: cache.join("volta"),
+            config: config.join("volta"),
+        })
+    }
+
+    /// The file recording that this layout was selected.
+    pub fn layout_file(&self) -> PathBuf {
+        self.config.join("layout")
+    }
+
+    /// Records the selection of this layout, so that it continues to be used without
+    /// `VOLTA_LAYOUT` being set.
+    pub fn save_selection(&self) -> Fallible<()> {
+        let layout_file = self.layout_file();
+        if is_selected_by(&layout_file) {
+            return Ok(());
+        }
+
+        fs::create_dir_all(&self.config).with_context(|_| ErrorDetails::CreateDirError {
+            dir: self.config.clone(),
+        })?;
+        fs::write(&layout_file, XDG_LAYOUT).with_context(|_| ErrorDetails::WriteLayoutFileError {
+            file: layout_file.clone(),
+        })
+    }
+}
+
+/// Determines whether the XDG layout is in use, either because `VOLTA_LAYOUT=xdg` is set or
+/// because it was selected on a previous run. Setting `VOLTA_LAYOUT=home` opts out.
+pub fn is_selected() -> Fallible<bool> {
+    match env::var(LAYOUT_VAR) {
+        Ok(layout) => Ok(layout == XDG_LAYOUT),
+        Err(_) => Ok(is_selected_by(&XdgDirs::current()?.layout_file())),
+    }
+}
+
+fn is_selected_by(layout_file: &Path) -> bool {
+    match read_file(layout_file) {
+        Ok(Some(layout)) => layout.trim() == XDG_LAYOUT,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_save_selection() {
+        let root = tempdir().expect("Could not create temp dir");
+        let dirs = XdgDirs {
+            data: root.path().join("data"),
+            cache: root.path().join("cache"),
+            config: root.path().join("config"),
+        };
+
+        assert!(!is_selected_by(&dirs.layout_file()));
+        dirs.save_selection().unwrap();
+        assert!(is_selected_by(&dirs.layout_file()));
+    }
+}
@@ -0,0 +1,75 @@
+//! Provides checks that the files in the Volta layout belong to the current user, so that
+//! files left behind by running `sudo volta` are reported before a command starts modifying
+//! the layout, rather than as a permission error partway through it. Also provides the
+//! repair run by `volta doctor --fix-permissions`.
+//!
+//! Only directories need to be writable by their owner: a read-only file in a writable
+//! directory can still be replaced or removed.
+
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::error::ErrorDetails;
+
+/// A problem with the ownership or permissions of a path in the Volta layout.
+#[derive(Debug, PartialEq)]
+pub enum Problem {
+    /// The path is owned by another user
+    NotOwned(PathBuf),
+    /// The directory is not writable by its owner
+    NotWritable(PathBuf),
+}
+
+impl From<Problem> for ErrorDetails {
+    fn from(problem: Problem) -> Self {
+        match problem {
+            Problem::NotOwned(path) => ErrorDetails::PathNotOwned { path },
+            Problem::NotWritable(path) => ErrorDetails::PathNotWritable { path },
+        }
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Problem::NotOwned(path) => write!(f, "{} is owned by another user", path.display()),
+            Problem::NotWritable(path) => write!(f, "{} is not writable", path.display()),
+        }
+    }
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(unix)] {
+        mod unix;
+        pub use self::unix::*;
+    } else {
+        use std::path::Path;
+
+        use volta_fail::Fallible;
+
+        /// Checks the ownership of the Volta layout. Windows does not have the same notion of
+        /// file ownership, so there is nothing to check.
+        pub fn check_layout() -> Fallible<()> {
+            Ok(())
+        }
+
+        /// Checks that a path can be modified, which Windows does not restrict by owner.
+        pub fn check_path(_path: &Path) -> Fallible<()> {
+            Ok(())
+        }
+
+        /// Points `HOME` at the home directory of the user who ran `sudo`, which doesn't exist
+        /// on Windows.
+        pub fn use_sudo_user_home() {}
+
+        /// Finds every path in the Volta layout with a problem.
+        pub fn find_problems() -> Fallible<Vec<Problem>> {
+            Ok(Vec::new())
+        }
+
+        /// Repairs every path in the Volta layout with a problem, returning how many were fixed.
+        pub fn fix_problems() -> Fallible<usize> {
+            Ok(0)
+        }
+    }
+}
@@ -0,0 +1,367 @@
+//! Provides the ownership checks and repairs on Unix.
+
+use std::env;
+use std::ffi::{CStr, CString, OsStr};
+use std::fs::{self, Metadata};
+use std::io;
+use std::os::unix::ffi::OsStrExt;
+use std::os::unix::fs::{MetadataExt, PermissionsExt};
+use std::path::{Path, PathBuf};
+
+use log::warn;
+use walkdir::WalkDir;
+
+use super::Problem;
+use crate::error::ErrorDetails;
+use crate::path;
+use volta_fail::{throw, Fallible, ResultExt};
+
+/// Checks the ownership of the directories and files that commands modify, so that a
+/// problem is reported before any changes are made.
+///
+/// Root can modify any file, so there is nothing to check when running as root. Under
+/// `sudo`, the files created by the command will belong to root, so this warns about that
+/// instead.
+pub fn check_layout() -> Fallible<()> {
+    let euid = unsafe { libc::geteuid() };
+    if euid == 0 {
+        if env::var_os("SUDO_UID").is_some() {
+            warn!(
+                "Running Volta with `sudo` leaves files owned by root in the Volta directory. \
+                 To give them back to your user, run `sudo volta doctor --fix-permissions`."
+            );
+        }
+        return Ok(());
+    }
+
+    let roots = path::layout_roots()?;
+    let mut paths = roots.clone();
+    for dir in path::layout_dirs()? {
+        // Also check the directories between the root and each layout directory, like
+        // `tools/image`, top-down so that the outermost problem is reported
+        let mut dirs: Vec<PathBuf> = match roots.iter().find(|root| dir.starts_with(root)) {
+            Some(root) => dir
+                .ancestors()
+                .take_while(|ancestor| *ancestor != root.as_path())
+                .map(Path::to_path_buf)
+                .collect(),
+            None => vec![dir],
+        };
+        dirs.reverse();
+        for dir in dirs {
+            if !paths.contains(&dir) {
+                paths.push(dir);
+            }
+        }
+    }
+    paths.push(path::layout_version_file()?);
+    paths.push(path::user_platform_file()?);
+    paths.push(path::node_index_file()?);
+    paths.push(path::node_index_expiry_file()?);
+
+    for path in paths {
+        // Paths that do not exist yet are created by the current user
+        if let Ok(metadata) = fs::symlink_metadata(&path) {
+            if let Some(problem) = problem(&path, &metadata, euid) {
+                throw!(ErrorDetails::from(problem));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that the current user can create, replace, or remove the given path, so that a
+/// problem is reported before fetching or removing a tool rather than partway through. This
+/// checks the path itself, if it exists, and the nearest existing directory containing it.
+pub fn check_path(path: &Path) -> Fallible<()> {
+    let euid = unsafe { libc::geteuid() };
+    if euid == 0 {
+        return Ok(());
+    }
+
+    match path_problem(path, euid) {
+        Some(problem) => throw!(ErrorDetails::from(problem)),
+        None => Ok(()),
+    }
+}
+
+/// Under `sudo`, points `HOME` at the home directory of the user who ran `sudo`. `sudo`
+/// usually resets it to root's, which would make the Volta layout root's own rather than the
+/// user's. This has to be called before any path in the layout is determined.
+pub fn use_sudo_user_home() {
+    if let Some(home) = sudo_user().and_then(user_home) {
+        env::set_var("HOME", home);
+    }
+}
+
+/// Finds every path in the Volta layout with a problem.
+pub fn find_problems() -> Fallible<Vec<Problem>> {
+    let (uid, _) = owner();
+    check_sudo_layout()?;
+    let mut problems = Vec::new();
+
+    for root in path::layout_roots()? {
+        for entry in WalkDir::new(&root)
+            .into_iter()
+            .filter_map(|entry| entry.ok())
+        {
+            if let Ok(metadata) = entry.metadata() {
+                problems.extend(problem(entry.path(), &metadata, uid));
+            }
+        }
+    }
+
+    Ok(problems)
+}
+
+/// Repairs every path in the Volta layout with a problem, returning how many were fixed.
+/// Paths owned by another user can only be given back with `sudo`, in which case they are
+/// given to the user who ran `sudo`.
+pub fn fix_problems() -> Fallible<usize> {
+    let (uid, gid) = owner();
+    check_sudo_layout()?;
+    let mut fixed = 0;
+
+    for root in path::layout_roots()? {
+        for entry in WalkDir::new(&root)
+            .into_iter()
+            .filter_map(|entry| entry.ok())
+        {
+            let path = entry.path();
+            let metadata = match entry.metadata() {
+                Ok(metadata) => metadata,
+                Err(_) => continue,
+            };
+
+            if problem(path, &metadata, uid).is_none() {
+                continue;
+            }
+
+            if metadata.uid() != uid {
+                lchown(path, uid, gid).with_context(|_| ErrorDetails::FixPermissionsError {
+                    path: path.to_path_buf(),
+                })?;
+            }
+
+            if metadata.is_dir() {
+                let mode = metadata.mode() | 0o700;
+                fs::set_permissions(path, fs::Permissions::from_mode(mode)).with_context(|_| {
+                    ErrorDetails::FixPermissionsError {
+                        path: path.to_path_buf(),
+                    }
+                })?;
+            }
+
+            fixed += 1;
+        }
+    }
+
+    Ok(fixed)
+}
+
+/// Determines the problem with a path, if any, given its metadata (without following
+/// symlinks) and the user who should own it.
+fn problem(path: &Path, metadata: &Metadata, uid: u32) -> Option<Problem> {
+    if metadata.uid() != uid {
+        Some(Problem::NotOwned(path.to_path_buf()))
+    } else if metadata.is_dir() && metadata.mode() & 0o200 == 0 {
+        Some(Problem::NotWritable(path.to_path_buf()))
+    } else {
+        None
+    }
+}
+
+/// Determines the problem with modifying a path, if any: either with the path itself or with
+/// the nearest existing directory that it would be created in or removed from.
+fn path_problem(path: &Path, uid: u32) -> Option<Problem> {
+    for ancestor in path.ancestors() {
+        if let Ok(metadata) = fs::symlink_metadata(ancestor) {
+            let problem = problem(ancestor, &metadata, uid);
+            if problem.is_some() || ancestor != path {
+                return problem;
+            }
+        }
+    }
+
+    None
+}
+
+/// The user and group who should own the Volta layout. Under `sudo`, that is the user who
+/// ran `sudo`, rather than root.
+fn owner() -> (u32, u32) {
+    if let (Some(uid), Some(gid)) = (sudo_user(), sudo_id("SUDO_GID")) {
+        return (uid, gid);
+    }
+
+    unsafe { (libc::geteuid(), libc::getegid()) }
+}
+
+/// The user who ran `sudo`, if running as root under `sudo`.
+fn sudo_user() -> Option<u32> {
+    if unsafe { libc::geteuid() } == 0 {
+        sudo_id("SUDO_UID")
+    } else {
+        None
+    }
+}
+
+fn sudo_id(var: &str) -> Option<u32> {
+    env::var(var).ok().and_then(|id| id.parse().ok())
+}
+
+/// Looks up the home directory of a user in the password database.
+fn user_home(uid: u32) -> Option<PathBuf> {
+    let passwd = unsafe { libc::getpwuid(uid) };
+    if passwd.is_null() {
+        return None;
+    }
+
+    let dir = unsafe { CStr::from_ptr((*passwd).pw_dir) };
+    Some(PathBuf::from(OsStr::from_bytes(dir.to_bytes())))
+}
+
+/// Under `sudo`, refuses to repair a layout that does not belong to the user who ran `sudo`,
+/// e.g. when `VOLTA_HOME` points at root's own Volta directory, rather than giving its files
+/// to that user.
+fn check_sudo_layout() -> Fallible<()> {
+    let uid = match sudo_user() {
+        Some(uid) => uid,
+        None => return Ok(()),
+    };
+    let home = match user_home(uid) {
+        Some(home) => home,
+        None => return Ok(()),
+    };
+
+    match foreign_root(&path::layout_roots()?, &home, uid) {
+        Some(path) => throw!(ErrorDetails::SudoUserLayoutError { path, home }),
+        None => Ok(()),
+    }
+}
+
+/// Finds a layout root that is neither in the user's home directory nor owned by the user.
+fn foreign_root(roots: &[PathBuf], home: &Path, uid: u32) -> Option<PathBuf> {
+    roots
+        .iter()
+        .find(|root| {
+            let owned = fs::symlink_metadata(root)
+                .map(|metadata| metadata.uid() == uid)
+                .unwrap_or(false);
+            !root.starts_with(home) && !owned
+        })
+        .cloned()
+}
+
+/// Changes the owner of a path, without following symlinks.
+fn lchown(path: &Path, uid: u32, gid: u32) -> io::Result<()> {
+    let path = CString::new(path.as_os_str().as_bytes())
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))?;
+
+    if unsafe { libc::lchown(path.as_ptr(), uid, gid) } == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{foreign_root, path_problem, problem};
+    use crate::permissions::Problem;
+    use std::fs;
+    use std::os::unix::fs::{MetadataExt, PermissionsExt};
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_problem_owned() {
+        let dir = tempdir().expect("Could not create temporary directory");
+        let metadata = fs::symlink_metadata(dir.path()).unwrap();
+
+        assert_eq!(problem(dir.path(), &metadata, metadata.uid()), None);
+    }
+
+    #[test]
+    fn test_problem_not_owned() {
+        let dir = tempdir().expect("Could not create temporary directory");
+        let metadata = fs::symlink_metadata(dir.path()).unwrap();
+
+        assert_eq!(
+            problem(dir.path(), &metadata, metadata.uid() + 1),
+            Some(Problem::NotOwned(dir.path().to_path_buf()))
+        );
+    }
+
+    #[test]
+    fn test_problem_not_writable() {
+        let dir = tempdir().expect("Could not create temporary directory");
+        let file = dir.path().join("platform.json");
+        fs::write(&file, "{}").unwrap();
+        fs::set_permissions(&file, fs::Permissions::from_mode(0o444)).unwrap();
+        fs::set_permissions(dir.path(), fs::Permissions::from_mode(0o555)).unwrap();
+
+        // A read-only file can still be replaced, so only the directory is a problem
+        let metadata = fs::symlink_metadata(&file).unwrap();
+        assert_eq!(problem(&file, &metadata, metadata.uid()), None);
+
+        let metadata = fs::symlink_metadata(dir.path()).unwrap();
+        assert_eq!(
+            problem(dir.path(), &metadata, metadata.uid()),
+            Some(Problem::NotWritable(dir.path().to_path_buf()))
+        );
+
+        fs::set_permissions(dir.path(), fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    #[test]
+    fn test_path_problem() {
+        let dir = tempdir().expect("Could not create temporary directory");
+        let image_dir = dir.path().join("image");
+        let node_dir = image_dir.join("node");
+        fs::create_dir_all(&node_dir).unwrap();
+        let uid = fs::symlink_metadata(dir.path()).unwrap().uid();
+
+        // A path that does not exist yet is created in its nearest existing directory
+        let version_dir = node_dir.join("12.4.0").join("6.9.0");
+        assert_eq!(path_problem(&version_dir, uid), None);
+
+        fs::set_permissions(&node_dir, fs::Permissions::from_mode(0o555)).unwrap();
+        assert_eq!(
+            path_problem(&version_dir, uid),
+            Some(Problem::NotWritable(node_dir.clone()))
+        );
+
+        // An existing path is checked itself, along with the directory it is removed from
+        assert_eq!(
+            path_problem(&node_dir, uid),
+            Some(Problem::NotWritable(node_dir.clone()))
+        );
+        fs::set_permissions(&node_dir, fs::Permissions::from_mode(0o755)).unwrap();
+        fs::set_permissions(&image_dir, fs::Permissions::from_mode(0o555)).unwrap();
+        assert_eq!(
+            path_problem(&node_dir, uid),
+            Some(Problem::NotWritable(image_dir.clone()))
+        );
+
+        fs::set_permissions(&image_dir, fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    #[test]
+    fn test_foreign_root() {
+        let dir = tempdir().expect("Could not create temporary directory");
+        let home = dir.path().join("home");
+        let other = dir.path().join("other");
+        fs::create_dir_all(&home).unwrap();
+        fs::create_dir_all(&other).unwrap();
+        let uid = fs::symlink_metadata(dir.path()).unwrap().uid();
+
+        // A root in the home directory belongs to the user, even before it is created
+        let volta_home = vec![home.join(".volta")];
+        assert_eq!(foreign_root(&volta_home, &home, uid + 1), None);
+
+        // A root elsewhere has to be owned by the user
+        let elsewhere = vec![home.join(".volta"), other.clone()];
+        assert_eq!(foreign_root(&elsewhere, &home, uid), None);
+        assert_eq!(foreign_root(&elsewhere, &home, uid + 1), Some(other));
+    }
+}
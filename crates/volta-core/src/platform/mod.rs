@@ -34,14 +34,21 @@ impl PlatformSpec {
             session.ensure_yarn(yarn_version)?;
         }
 
+        let bundled_npm = load_default_npm_version(&self.node_runtime)?;
+        let npm = match self.npm {
+            Some(ref version) if version != &bundled_npm => {
+                session.ensure_npm(version)?;
+                Some(version.clone())
+            }
+            _ => None,
+        };
+
         Ok(Image {
             node: NodeVersion {
                 runtime: self.node_runtime.clone(),
-                npm: match self.npm {
-                    Some(ref version) => version.clone(),
-                    None => load_default_npm_version(&self.node_runtime)?,
-                },
+                npm: bundled_npm,
             },
+            npm,
             yarn: self.yarn.clone(),
         })
     }
@@ -50,22 +57,35 @@ impl PlatformSpec {
 /// A platform image.
 #[derive(Clone, Debug)]
 pub struct Image {
-    /// The pinned version of Node.
+    /// The pinned version of Node, with the version of npm bundled with it.
     pub node: NodeVersion,
+    /// The version of npm overlaid on the Node image, if it differs from the bundled npm.
+    pub npm: Option<Version>,
     /// The pinned version of Yarn, if any.
     pub yarn: Option<Version>,
 }
 
 impl Image {
+    /// Returns the version of npm available in this image.
+    pub fn npm(&self) -> &Version {
+        self.npm.as_ref().unwrap_or(&self.node.npm)
+    }
+
     fn bins(&self) -> Fallible<Vec<PathBuf>> {
         let node_str = self.node.runtime.to_string();
         let npm_str = self.node.npm.to_string();
-        // ISSUE(#292): Install npm, and handle using that
-        let mut bins = vec![path::node_image_bin_dir(&node_str, &npm_str)?];
+        let mut bins = Vec::new();
+        // The overlaid npm must come first in the PATH to shadow the bundled npm
+        if let Some(ref npm) = self.npm {
+            bins.push(path::npm_image_bin_dir(&npm.to_string())?);
+        }
+        bins.push(path::node_image_bin_dir(&node_str, &npm_str)?);
         if let Some(ref yarn) = self.yarn {
             let yarn_str = yarn.to_string();
             bins.push(path::yarn_image_bin_dir(&yarn_str)?);
         }
+        // Global packages installed with the redirected npm prefix come after the image
+        bins.push(path::npm_prefix_bin_dir()?);
         Ok(bins)
     }
 
@@ -175,6 +195,14 @@ mod test {
             .join("bin");
         let expected_yarn_bin = yarn_bin.as_path().to_str().unwrap();
 
+        let prefix_bin = volta_home()
+            .unwrap()
+            .join("tools")
+            .join("user")
+            .join("npm")
+            .join("bin");
+        let expected_prefix_bin = prefix_bin.as_path().to_str().unwrap();
+
         let v123 = Version::parse("1.2.3").unwrap();
         let v457 = Version::parse("4.5.7").unwrap();
         let v643 = Version::parse("6.4.3").unwrap();
@@ -184,12 +212,16 @@ mod test {
                 runtime: v123.clone(),
                 npm: v643.clone(),
             },
+            npm: None,
             yarn: None,
         };
 
         assert_eq!(
             no_yarn_image.path().unwrap().into_string().unwrap(),
-            format!("{}:/usr/bin:/blah:/doesnt/matter/bin", expected_node_bin),
+            format!(
+                "{}:{}:/usr/bin:/blah:/doesnt/matter/bin",
+                expected_node_bin, expected_prefix_bin
+            ),
         );
 
         let with_yarn_image = Image {
@@ -197,14 +229,41 @@ mod test {
                 runtime: v123.clone(),
                 npm: v643.clone(),
             },
+            npm: None,
             yarn: Some(v457.clone()),
         };
 
         assert_eq!(
             with_yarn_image.path().unwrap().into_string().unwrap(),
             format!(
-                "{}:{}:/usr/bin:/blah:/doesnt/matter/bin",
-                expected_node_bin, expected_yarn_bin
+                "{}:{}:{}:/usr/bin:/blah:/doesnt/matter/bin",
+                expected_node_bin, expected_yarn_bin, expected_prefix_bin
+            ),
+        );
+
+        let npm_bin = volta_home()
+            .unwrap()
+            .join("tools")
+            .join("image")
+            .join("npm")
+            .join("6.9.0")
+            .join("bin");
+        let expected_npm_bin = npm_bin.as_path().to_str().unwrap();
+
+        let with_npm_image = Image {
+            node: NodeVersion {
+                runtime: v123.clone(),
+                npm: v643.clone(),
+            },
+            npm: Some(Version::parse("6.9.0").unwrap()),
+            yarn: None,
+        };
+
+        assert_eq!(
+            with_npm_image.path().unwrap().into_string().unwrap(),
+            format!(
+                "{}:{}:{}:/usr/bin:/blah:/doesnt/matter/bin",
+                expected_npm_bin, expected_node_bin, expected_prefix_bin
             ),
         );
     }
@@ -242,6 +301,9 @@ mod test {
             .join("bin");
         let expected_yarn_bin = yarn_bin.as_path().to_str().unwrap();
 
+        let prefix_bin = volta_home().unwrap().join("tools").join("user").join("npm");
+        let expected_prefix_bin = prefix_bin.as_path().to_str().unwrap();
+
         let v123 = Version::parse("1.2.3").unwrap();
         let v457 = Version::parse("4.5.7").unwrap();
         let v643 = Version::parse("6.4.3").unwrap();
@@ -251,12 +313,16 @@ mod test {
                 runtime: v123.clone(),
                 npm: v643.clone(),
             },
+            npm: None,
             yarn: None,
         };
 
         assert_eq!(
             no_yarn_image.path().unwrap().into_string().unwrap(),
-            format!("{};C:\\\\somebin;D:\\\\ProbramFlies", expected_node_bin),
+            format!(
+                "{};{};C:\\\\somebin;D:\\\\ProbramFlies",
+                expected_node_bin, expected_prefix_bin
+            ),
         );
 
         let with_yarn_image = Image {
@@ -264,14 +330,15 @@ mod test {
                 runtime: v123.clone(),
                 npm: v643.clone(),
             },
+            npm: None,
             yarn: Some(v457.clone()),
         };
 
         assert_eq!(
             with_yarn_image.path().unwrap().into_string().unwrap(),
             format!(
-                "{};{};C:\\\\somebin;D:\\\\ProbramFlies",
-                expected_node_bin, expected_yarn_bin
+                "{};{};{};C:\\\\somebin;D:\\\\ProbramFlies",
+                expected_node_bin, expected_yarn_bin, expected_prefix_bin
             ),
         );
     }
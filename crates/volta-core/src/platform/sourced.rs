@@ -51,7 +51,13 @@ impl SourcedPlatformSpec {
     }
 
     pub fn checkout(self, session: &mut Session) -> Fallible<SourcedImage> {
-        let image = self.platform.checkout(session)?;
+        let platform = match self.source {
+            Source::Default => self.platform,
+            Source::Project | Source::ProjectNodeDefaultYarn => {
+                session.resolve_project_npm(self.platform)?
+            }
+        };
+        let image = platform.checkout(session)?;
         Ok(SourcedImage {
             image,
             source: self.source,
@@ -84,6 +90,10 @@ impl SourcedImage {
         &self.image.node
     }
 
+    pub fn npm(&self) -> &Version {
+        self.image.npm()
+    }
+
     pub fn yarn(&self) -> Option<&Version> {
         self.image.yarn.as_ref()
     }
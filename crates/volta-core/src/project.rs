@@ -15,9 +15,28 @@ use crate::error::ErrorDetails;
 use crate::manifest::{serial, Manifest};
 use crate::path;
 use crate::platform::PlatformSpec;
+use crate::timing::{self, Phase};
 use crate::tool::{load_default_npm_version, BinConfig, NodeVersion};
 use log::debug;
-use volta_fail::{Fallible, ResultExt};
+use volta_fail::{throw, Fallible, ResultExt};
+
+/// Returns the directory that project detection starts from: the directory in
+/// `VOLTA_PROJECT` if it is set, or else the current working directory.
+pub(crate) fn base_dir() -> Fallible<PathBuf> {
+    let current_dir = env::current_dir().with_context(|_| ErrorDetails::CurrentDirError)?;
+
+    match crate::env::project_dir() {
+        Some(dir) => {
+            // A relative directory is resolved against the current directory
+            let dir = current_dir.join(dir);
+            if !dir.is_dir() {
+                throw!(ErrorDetails::ProjectDirNotFound { dir });
+            }
+            Ok(dir)
+        }
+        None => Ok(current_dir),
+    }
+}
 
 /// A lazily loaded Project
 pub struct LazyProject {
@@ -32,9 +51,7 @@ impl LazyProject {
     }
 
     pub fn get(&self) -> Fallible<Option<Rc<Project>>> {
-        let project = self
-            .project
-            .try_borrow_with(|| Project::for_current_dir())?;
+        let project = self.project.try_borrow_with(|| Project::for_base_dir())?;
         Ok(project.clone())
     }
 }
@@ -46,12 +63,11 @@ pub struct Project {
 }
 
 impl Project {
-    /// Returns the Node project containing the current working directory,
+    /// Returns the Node project containing the project base directory,
     /// if any.
-    fn for_current_dir() -> Fallible<Option<Rc<Project>>> {
-        let current_dir: &Path =
-            &env::current_dir().with_context(|_| ErrorDetails::CurrentDirError)?;
-        Self::for_dir(&current_dir)
+    fn for_base_dir() -> Fallible<Option<Rc<Project>>> {
+        let _span = timing::span(Phase::ProjectDetection);
+        Self::for_dir(&base_dir()?)
     }
 
     /// Returns the Node project for the input directory, if any.
@@ -100,7 +116,7 @@ impl Project {
             let config_path = path::user_tool_bin_config(name)?;
             if config_path.exists() {
                 let config = BinConfig::from_file(config_path)?;
-                return Ok(self.has_direct_dependency(&config.package));
+                return Ok(!config.alias && self.has_direct_dependency(&config.package));
             }
         }
         Ok(false)
@@ -117,7 +133,10 @@ impl Project {
         let bin_config = config_path.map(BinConfig::from_file).transpose()?;
 
         let matching_config = bin_config.and_then(|config| {
-            if self.has_direct_dependency(&config.package) && &config.version == version {
+            if !config.alias
+                && self.has_direct_dependency(&config.package)
+                && &config.version == version
+            {
                 Some(config)
             } else {
                 None
@@ -173,7 +192,7 @@ impl Project {
         if let Some(platform) = self.manifest().platform() {
             let toolchain = serial::ToolchainSpec::new(
                 platform.node_runtime.to_string(),
-                platform.npm.as_ref().map(|npm| npm.to_string()),
+                self.manifest().npm_str(),
                 Some(yarn_version.to_string()),
             );
             Manifest::update_toolchain(toolchain, self.package_file())
@@ -53,7 +53,7 @@ where
 
                 let image = platform.checkout(session)?;
                 let path = image.path()?;
-                return Ok(ToolCommand::project_local(&path_to_bin, args, &path));
+                return ToolCommand::project_local(&path_to_bin, args, &path);
             }
 
             // if there's no platform available, pass through to existing PATH.
@@ -90,7 +90,7 @@ where
             ),
             None => ToolCommand::direct(&tool_path, args, &path),
         };
-        return Ok(cmd);
+        return cmd;
     }
 
     // at this point, there is no project or user toolchain
@@ -120,12 +120,17 @@ pub struct DefaultBinary {
 
 impl DefaultBinary {
     pub fn from_config(bin_config: BinConfig, session: &mut Session) -> Fallible<Self> {
-        let bin_path = bin_full_path(
-            &bin_config.package,
-            &bin_config.version,
-            &bin_config.name,
-            &bin_config.path,
-        )?;
+        // An alias runs a tool from its platform image, which is found on the PATH
+        let bin_path = if bin_config.alias {
+            PathBuf::from(&bin_config.path)
+        } else {
+            bin_full_path(
+                &bin_config.package,
+                &bin_config.version,
+                &bin_config.name,
+                &bin_config.path,
+            )?
+        };
 
         // If the user does not have yarn set in the platform for this binary, use the default
         // This is necessary because some tools (e.g. ember-cli with the `--yarn` option) invoke `yarn`
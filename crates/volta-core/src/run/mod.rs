@@ -12,6 +12,7 @@ use crate::path;
 use crate::platform::System;
 use crate::session::Session;
 use crate::signal::pass_control_to_shim;
+use log::debug;
 use volta_fail::{Fallible, ResultExt};
 
 mod binary;
@@ -22,6 +23,13 @@ mod yarn;
 
 pub use binary::DefaultBinary;
 
+/// The environment variables npm reads its global `prefix` setting from. npm accepts both
+/// the lowercase and uppercase forms, so a user-provided value in either one takes precedence.
+const NPM_PREFIX_VARS: [&'static str; 2] = ["npm_config_prefix", "NPM_CONFIG_PREFIX"];
+
+/// The environment variables npm reads its `cache` setting from.
+const NPM_CACHE_VARS: [&'static str; 2] = ["npm_config_cache", "NPM_CONFIG_CACHE"];
+
 /// Distinguish global `add` commands in npm or yarn from all others.
 enum CommandArg {
     /// The command is a *global* add command.
@@ -31,7 +39,7 @@ enum CommandArg {
 }
 
 pub fn execute_tool(session: &mut Session) -> Fallible<ExitStatus> {
-    path::ensure_volta_dirs_exist()?;
+    path::ensure_layout()?;
 
     let mut args = args_os();
     let exe = get_tool_name(&mut args)?;
@@ -61,27 +69,33 @@ struct ToolCommand {
 
 impl ToolCommand {
     /// Build a ToolCommand that is directly calling a tool in the Volta directory
-    fn direct<A>(exe: &OsStr, args: A, path_var: &OsStr) -> Self
+    fn direct<A>(exe: &OsStr, args: A, path_var: &OsStr) -> Fallible<Self>
     where
         A: IntoIterator<Item = OsString>,
     {
-        ToolCommand {
-            command: command_for(exe, args, path_var),
+        let mut command = command_for(exe, args, path_var);
+        redirect_npm_dirs(&mut command)?;
+
+        Ok(ToolCommand {
+            command,
             on_failure: ErrorDetails::BinaryExecError,
-        }
+        })
     }
 
     /// Build a ToolCommand that is calling a binary in the current project's `node_modules/bin`
-    fn project_local<A>(exe: &OsStr, args: A, path_var: &OsStr) -> Self
+    fn project_local<A>(exe: &OsStr, args: A, path_var: &OsStr) -> Fallible<Self>
     where
         A: IntoIterator<Item = OsString>,
     {
-        ToolCommand {
-            command: command_for(exe, args, path_var),
+        let mut command = command_for(exe, args, path_var);
+        redirect_npm_dirs(&mut command)?;
+
+        Ok(ToolCommand {
+            command,
             on_failure: ErrorDetails::ProjectLocalBinaryExecError {
                 command: exe.to_string_lossy().to_string(),
             },
-        }
+        })
     }
 
     /// Build a ToolCommand that is calling a command that Volta couldn't find
@@ -137,6 +151,27 @@ where
     command
 }
 
+/// Points npm's global prefix and cache at user-writable directories outside of the Node image
+///
+/// By default, npm uses the Node installation as its prefix, so `npm root -g` and any global
+/// writes (e.g. `npm install -g` with `VOLTA_UNSAFE_GLOBAL` set) would land inside the shared
+/// image directory. Redirecting the prefix keeps the images unmodified after they are unpacked,
+/// and the cache is kept with Volta's other caches. If the user has already configured either
+/// setting through the environment, we leave it alone.
+fn redirect_npm_dirs(command: &mut Command) -> Fallible<()> {
+    if NPM_PREFIX_VARS.iter().all(|var| env::var_os(var).is_none()) {
+        let prefix = path::npm_prefix_dir()?;
+        debug!("Setting npm global prefix to '{}'", prefix.display());
+        command.env(NPM_PREFIX_VARS[0], prefix);
+    }
+    if NPM_CACHE_VARS.iter().all(|var| env::var_os(var).is_none()) {
+        let cache = path::npm_cache_dir()?;
+        debug!("Setting npm cache to '{}'", cache.display());
+        command.env(NPM_CACHE_VARS[0], cache);
+    }
+    Ok(())
+}
+
 fn intercept_global_installs() -> bool {
     // We should only intercept global installs if the VOLTA_UNSAFE_GLOBAL variable is not set
     env::var_os(UNSAFE_GLOBAL).is_none()
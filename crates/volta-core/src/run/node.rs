@@ -26,7 +26,7 @@ where
 
             let image = platform.checkout(session)?;
             let path = image.path()?;
-            Ok(ToolCommand::direct(OsStr::new("node"), args, &path))
+            ToolCommand::direct(OsStr::new("node"), args, &path)
         }
         None => {
             debug!("Could not find Volta-managed node, delegating to system");
@@ -30,10 +30,10 @@ where
                 Source::Project | Source::ProjectNodeDefaultYarn => "project",
                 Source::Default => "default",
             };
-            let version = tool_version("npm", &image.node().npm);
+            let version = tool_version("npm", image.npm());
             debug!("Using {} from {} configuration", version, source);
 
-            Ok(ToolCommand::direct(OsStr::new("npm"), args, &path))
+            ToolCommand::direct(OsStr::new("npm"), args, &path)
         }
         None => {
             debug!("Could not find Volta-managed npm, delegating to system");
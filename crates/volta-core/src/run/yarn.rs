@@ -34,7 +34,7 @@ where
 
             let image = platform.checkout(session)?;
             let path = image.path()?;
-            Ok(ToolCommand::direct(OsStr::new("yarn"), args, &path))
+            ToolCommand::direct(OsStr::new("yarn"), args, &path)
         }
         None => {
             debug!("Could not find Volta-managed yarn, delegating to system");
@@ -3,15 +3,17 @@
 //! hook configuration, and the state of the local inventory.
 
 use std::fmt::{self, Display, Formatter};
+use std::path::PathBuf;
 use std::process::exit;
 use std::rc::Rc;
 
 use crate::event::EventLog;
-use crate::hook::{HookConfig, LazyHookConfig, Publish};
+use crate::hook::{HookConfig, LazyHookConfig};
 use crate::inventory::{Inventory, LazyInventory};
+use crate::path;
 use crate::platform::{PlatformSpec, SourcedPlatformSpec};
 use crate::project::{LazyProject, Project};
-use crate::tool::{Node, Yarn};
+use crate::tool::{load_default_npm_version, resolve_npm_overlay, Node, Npm, Yarn};
 use crate::toolchain::{LazyToolchain, Toolchain};
 
 use log::debug;
@@ -41,6 +43,13 @@ pub enum ActivityKind {
     Shim,
     Completions,
     Which,
+    Cache,
+    Alias,
+    ImportNode,
+    Init,
+    Export,
+    Import,
+    Doctor,
 }
 
 impl Display for ActivityKind {
@@ -67,6 +76,13 @@ impl Display for ActivityKind {
             &ActivityKind::Shim => "shim",
             &ActivityKind::Completions => "completions",
             &ActivityKind::Which => "which",
+            &ActivityKind::Cache => "cache",
+            &ActivityKind::Alias => "alias",
+            &ActivityKind::ImportNode => "import-node",
+            &ActivityKind::Init => "init",
+            &ActivityKind::Export => "export",
+            &ActivityKind::Import => "import",
+            &ActivityKind::Doctor => "doctor",
         };
         f.write_str(s)
     }
@@ -141,7 +157,10 @@ impl Session {
             .map(|platform| Rc::new(platform.clone())))
     }
 
-    /// Returns the current project's pinned platform image, if any.
+    /// Returns the platform specified by the current project, if any.
+    ///
+    /// If the project specifies a range for npm, the platform has no npm version until it is
+    /// checked out, see `resolve_project_npm`.
     pub fn project_platform(&self) -> Fallible<Option<Rc<PlatformSpec>>> {
         if let Some(ref project) = self.project()? {
             return Ok(project.platform());
@@ -149,6 +168,31 @@ impl Session {
         Ok(None)
     }
 
+    /// Resolves the range for npm specified by the current project, if any, into a version for
+    /// a platform from the project. This can fetch Node or query the npm registry, so it is only
+    /// done when the platform is checked out.
+    pub(crate) fn resolve_project_npm(
+        &mut self,
+        platform: Rc<PlatformSpec>,
+    ) -> Fallible<Rc<PlatformSpec>> {
+        let requirement = match self.project()? {
+            Some(project) => project.manifest().npm_requirement.clone(),
+            None => None,
+        };
+
+        match requirement {
+            Some(ref requirement) if platform.npm.is_none() => {
+                let npm = resolve_npm_overlay(&platform.node_runtime, requirement, self)?;
+                Ok(Rc::new(PlatformSpec {
+                    node_runtime: platform.node_runtime.clone(),
+                    npm,
+                    yarn: platform.yarn.clone(),
+                }))
+            }
+            _ => Ok(platform),
+        }
+    }
+
     /// Produces a reference to the current inventory.
     pub fn inventory(&self) -> Fallible<&Inventory> {
         self.inventory.get()
@@ -176,6 +220,12 @@ impl Session {
 
     /// Ensures that a specific Node version has been fetched and unpacked
     pub(crate) fn ensure_node(&mut self, version: &Version) -> Fallible<()> {
+        // Checking for the unpacked image first means the shims never need to load the
+        // inventory when running a tool that is already available
+        if node_image_exists(version) {
+            return Ok(());
+        }
+
         let inventory = self.inventory.get_mut()?;
 
         if !inventory.node.versions.contains(version) {
@@ -185,8 +235,27 @@ impl Session {
         Ok(())
     }
 
+    /// Ensures that a specific npm version has been fetched and unpacked
+    pub(crate) fn ensure_npm(&mut self, version: &Version) -> Fallible<()> {
+        if image_exists(path::npm_image_dir(&version.to_string())) {
+            return Ok(());
+        }
+
+        let inventory = self.inventory.get_mut()?;
+
+        if !inventory.npm.versions.contains(version) {
+            Npm::new(version.clone()).fetch_internal(self)?;
+        }
+
+        Ok(())
+    }
+
     /// Ensures that a specific Yarn version has been fetched and unpacked
     pub(crate) fn ensure_yarn(&mut self, version: &Version) -> Fallible<()> {
+        if image_exists(path::yarn_image_dir(&version.to_string())) {
+            return Ok(());
+        }
+
         let inventory = self.inventory.get_mut()?;
 
         if !inventory.yarn.versions.contains(version) {
@@ -210,7 +279,11 @@ impl Session {
     }
 
     fn publish_to_event_log(mut self) {
-        match publish_plugin(&self.hooks) {
+        if self.event_log.is_empty() {
+            return;
+        }
+
+        match self.hooks.publish() {
             Ok(plugin) => {
                 self.event_log.publish(plugin);
             }
@@ -231,10 +304,16 @@ impl Session {
     }
 }
 
-fn publish_plugin(hooks: &LazyHookConfig) -> Fallible<Option<&Publish>> {
-    let hooks = hooks.get()?;
-    let publish = hooks.events().and_then(|events| events.publish.as_ref());
-    Ok(publish)
+fn node_image_exists(version: &Version) -> bool {
+    let version_str = version.to_string();
+    match load_default_npm_version(version) {
+        Ok(npm) => image_exists(path::node_image_dir(&version_str, &npm.to_string())),
+        Err(_) => false,
+    }
+}
+
+fn image_exists(dir: Fallible<PathBuf>) -> bool {
+    dir.map(|dir| dir.is_dir()).unwrap_or(false)
 }
 
 #[cfg(test)]
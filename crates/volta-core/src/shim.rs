@@ -4,6 +4,7 @@ use std::{fs, io};
 
 use crate::error::ErrorDetails;
 use crate::path;
+use crate::timing::{self, Phase};
 use volta_fail::{throw, FailExt, Fallible};
 
 #[derive(PartialEq)]
@@ -14,7 +15,7 @@ pub enum ShimResult {
     DoesntExist,
 }
 
-fn is_3p_shim(name: &str) -> bool {
+pub(crate) fn is_3p_shim(name: &str) -> bool {
     match name {
         "node" | "yarn" | "npm" | "npx" => false,
         _ => true,
@@ -22,6 +23,7 @@ fn is_3p_shim(name: &str) -> bool {
 }
 
 pub fn create(shim_name: &str) -> Fallible<ShimResult> {
+    let _span = timing::span(Phase::ShimGeneration);
     let executable = path::shim_executable()?;
     let shim = path::shim_file(shim_name)?;
 
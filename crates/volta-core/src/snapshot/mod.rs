@@ -0,0 +1,163 @@
+//! Provides snapshots of the user's toolchain: the default platform, the installed packages,
+//! and the aliases. `volta export` writes a snapshot as a toolchain file, and `volta import`
+//! restores it, for example on a new machine.
+
+use std::fs::read_to_string;
+use std::path::Path;
+
+use crate::alias;
+use crate::error::ErrorDetails;
+use crate::session::Session;
+use crate::style::{success_prefix, tool_version};
+use crate::tool::{load_default_npm_version, Spec};
+use crate::version::VersionSpec;
+use log::info;
+use semver::Version;
+use volta_fail::{throw, Fallible, ResultExt};
+
+mod serial;
+
+/// Captures the current toolchain as the JSON contents of a toolchain file.
+pub fn export(session: &Session) -> Fallible<String> {
+    let platform = session.user_platform()?;
+
+    let snapshot = serial::Snapshot {
+        node: platform.as_ref().map(|platform| serial::NodeVersion {
+            runtime: platform.node_runtime.to_string(),
+            npm: platform.npm.as_ref().map(Version::to_string),
+        }),
+        yarn: platform
+            .as_ref()
+            .and_then(|platform| platform.yarn.as_ref())
+            .map(Version::to_string),
+        packages: session
+            .inventory()?
+            .packages
+            .iter()
+            .map(|config| (config.name.clone(), config.version.to_string()))
+            .collect(),
+        aliases: alias::list()?
+            .into_iter()
+            .map(|alias| (alias.name, tool_version(&alias.tool, &alias.version)))
+            .collect(),
+    };
+
+    serde_json::to_string_pretty(&snapshot).with_context(|_| ErrorDetails::StringifySnapshotError)
+}
+
+/// Fetches and installs everything listed in a toolchain file. When the import is `frozen`,
+/// every version in the file must be exact, so that nothing is resolved to a different
+/// version; this is checked before anything is installed.
+pub fn import(file: &Path, frozen: bool, session: &mut Session) -> Fallible<()> {
+    let src = read_to_string(file).with_context(|_| ErrorDetails::ReadSnapshotError {
+        file: file.to_path_buf(),
+    })?;
+    let snapshot: serial::Snapshot =
+        serde_json::from_str(&src).with_context(|_| ErrorDetails::ParseSnapshotError {
+            file: file.to_path_buf(),
+        })?;
+
+    let (node, npm) = match snapshot.node {
+        Some(node) => (
+            Some(Spec::Node(VersionSpec::parse(&node.runtime)?)),
+            node.npm.map(VersionSpec::parse_version).transpose()?,
+        ),
+        None => (None, None),
+    };
+
+    let mut tools = Vec::new();
+    if let Some(yarn) = snapshot.yarn {
+        tools.push(Spec::Yarn(VersionSpec::parse(&yarn)?));
+    }
+    for (name, version) in snapshot.packages {
+        tools.push(Spec::Package(name, VersionSpec::parse(&version)?));
+    }
+
+    let aliases = snapshot
+        .aliases
+        .into_iter()
+        .map(|(name, tool)| Ok((name, Spec::try_from_str(&tool)?)))
+        .collect::<Fallible<Vec<_>>>()?;
+
+    if frozen {
+        let specs = node
+            .iter()
+            .chain(tools.iter())
+            .chain(aliases.iter().map(|(_, spec)| spec));
+        for spec in specs {
+            if !is_exact(spec) {
+                throw!(ErrorDetails::FrozenVersionNotExact {
+                    tool: spec.to_string(),
+                });
+            }
+        }
+    }
+
+    // Node is installed first, since the other tools are installed with the default Node
+    if let Some(node) = node {
+        node.resolve(session)?.install(session)?;
+        if let Some(npm) = npm {
+            import_npm(&npm, session)?;
+        }
+    }
+
+    for tool in tools {
+        tool.resolve(session)?.install(session)?;
+    }
+
+    for (name, spec) in aliases {
+        let alias = alias::create(&name, spec, session)?;
+        info!("{} created alias {}", success_prefix(), alias);
+    }
+
+    Ok(())
+}
+
+/// Sets the default npm, which only needs to be fetched if it is not the npm bundled with
+/// the default Node.
+fn import_npm(npm: &Version, session: &mut Session) -> Fallible<()> {
+    let node = session
+        .user_platform()?
+        .ok_or(ErrorDetails::NoPlatform)?
+        .node_runtime
+        .clone();
+    if load_default_npm_version(&node)? == *npm {
+        return Ok(());
+    }
+
+    session.ensure_npm(npm)?;
+    session.toolchain_mut()?.set_active_npm(npm)?;
+    info!(
+        "{} set {} as default",
+        success_prefix(),
+        tool_version("npm", npm)
+    );
+
+    Ok(())
+}
+
+fn is_exact(spec: &Spec) -> bool {
+    match spec {
+        Spec::Node(version)
+        | Spec::Npm(version)
+        | Spec::Yarn(version)
+        | Spec::Package(_, version) => match version {
+            VersionSpec::Exact(_) => true,
+            _ => false,
+        },
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_exact() {
+        assert!(is_exact(&Spec::try_from_str("node@12.4.0").unwrap()));
+        assert!(is_exact(&Spec::try_from_str("cowsay@1.4.0").unwrap()));
+        assert!(!is_exact(&Spec::try_from_str("node@12").unwrap()));
+        assert!(!is_exact(&Spec::try_from_str("yarn@latest").unwrap()));
+        assert!(!is_exact(&Spec::try_from_str("cowsay@^1.4.0").unwrap()));
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// The contents of a toolchain file. Versions are kept as strings, so that a hand-written
+/// file can also use version requirements, like `"node": { "runtime": "12" }`.
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+pub struct Snapshot {
+    #[serde(default)]
+    pub node: Option<NodeVersion>,
+    #[serde(default)]
+    pub yarn: Option<String>,
+    /// Installed packages, by name
+    #[serde(default)]
+    pub packages: BTreeMap<String, String>,
+    /// Aliases, from their names to the tools they run, e.g. `node@10.16.0`
+    #[serde(default)]
+    pub aliases: BTreeMap<String, String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct NodeVersion {
+    pub runtime: String,
+    #[serde(default)]
+    pub npm: Option<String>,
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    const SNAPSHOT_JSON: &'static str = r#"{
+  "node": {
+    "runtime": "12.4.0",
+    "npm": "6.9.0"
+  },
+  "yarn": "1.16.0",
+  "packages": {
+    "cowsay": "1.4.0",
+    "typescript": "3.4.1"
+  },
+  "aliases": {
+    "node10": "node@10.16.0"
+  }
+}"#;
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let mut packages = BTreeMap::new();
+        packages.insert("typescript".to_string(), "3.4.1".to_string());
+        packages.insert("cowsay".to_string(), "1.4.0".to_string());
+        let mut aliases = BTreeMap::new();
+        aliases.insert("node10".to_string(), "node@10.16.0".to_string());
+        let snapshot = Snapshot {
+            node: Some(NodeVersion {
+                runtime: "12.4.0".to_string(),
+                npm: Some("6.9.0".to_string()),
+            }),
+            yarn: Some("1.16.0".to_string()),
+            packages,
+            aliases,
+        };
+
+        assert_eq!(
+            serde_json::to_string_pretty(&snapshot).unwrap(),
+            SNAPSHOT_JSON
+        );
+        assert_eq!(
+            serde_json::from_str::<Snapshot>(SNAPSHOT_JSON).unwrap(),
+            snapshot
+        );
+    }
+
+    #[test]
+    fn test_snapshot_defaults() {
+        let snapshot: Snapshot = serde_json::from_str(r#"{ "yarn": "1" }"#).unwrap();
+        assert_eq!(
+            snapshot,
+            Snapshot {
+                yarn: Some("1".to_string()),
+                ..Snapshot::default()
+            }
+        );
+    }
+}
@@ -1,19 +1,198 @@
 //! The view layer of Volta, with utilities for styling command-line output.
+use std::env;
+use std::fmt::Display;
+use std::path::Path;
+
 use archive::Origin;
+use atty::Stream;
 use cfg_if::cfg_if;
 use console::{style, StyledObject};
 use failure::Fail;
 use indicatif::{ProgressBar, ProgressStyle};
 use term_size;
+use textwrap::{NoHyphenation, Wrapper};
 
 const MAX_WIDTH: usize = 100;
 const MAX_PROGRESS_WIDTH: usize = 40;
+const WRAP_INDENT: &'static str = "    ";
+/// Characters that may surround a path or URL in a message, e.g. quotes or punctuation
+const LEADING_PUNCTUATION: &[char] = &['\'', '"', '`', '('];
+const TRAILING_PUNCTUATION: &[char] = &['\'', '"', '`', ')', '.', ',', ':', ';'];
 
 /// Generate the styled prefix for a success message
-pub(crate) fn success_prefix() -> StyledObject<&'static str> {
+pub fn success_prefix() -> StyledObject<&'static str> {
     style("success:").green().bold()
 }
 
+/// Formats an error message for stderr after the given prefix, e.g. `error:`
+///
+/// In a terminal, the message is wrapped to the terminal width without breaking words, with
+/// wrapped lines indented, and file paths and URLs are rendered as hyperlinks if the terminal
+/// supports them. Otherwise, the message is left as plain text.
+pub fn display_error<D>(prefix: &str, message: &D) -> String
+where
+    D: Display,
+{
+    format!(
+        "{} {}",
+        style(prefix).red().bold(),
+        format_message(prefix, message)
+    )
+}
+
+/// Formats a warning message for stderr after the given prefix, e.g. `warning:`, in the same
+/// way as `display_error`
+pub fn display_warning<D>(prefix: &str, message: &D) -> String
+where
+    D: Display,
+{
+    format!(
+        "{} {}",
+        style(prefix).yellow().bold(),
+        format_message(prefix, message)
+    )
+}
+
+fn format_message<D>(prefix: &str, message: &D) -> String
+where
+    D: Display,
+{
+    let message = message.to_string();
+    if !atty::is(Stream::Stderr) {
+        return message;
+    }
+
+    let wrapped = match text_width() {
+        Some(width) => wrap_message(prefix.len() + 1, &message, width),
+        None => message,
+    };
+
+    if supports_hyperlinks() {
+        hyperlink_message(&wrapped)
+    } else {
+        wrapped
+    }
+}
+
+/// Wraps each line of a message to the given width. Wrapped lines are indented relative to the
+/// line they continue, and the first line leaves room for the prefix it follows.
+fn wrap_message(prefix_width: usize, message: &str, width: usize) -> String {
+    let prefix_indent = " ".repeat(prefix_width);
+
+    message
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let content = line.trim_start();
+            if content.is_empty() {
+                return String::new();
+            }
+
+            let line_indent = &line[..line.len() - content.len()];
+            let first_indent = if index == 0 {
+                format!("{}{}", prefix_indent, line_indent)
+            } else {
+                line_indent.to_string()
+            };
+            let subsequent_indent = format!("{}{}", line_indent, WRAP_INDENT);
+
+            let wrapped = Wrapper::with_splitter(width, NoHyphenation)
+                .initial_indent(&first_indent)
+                .subsequent_indent(&subsequent_indent)
+                .break_words(false)
+                .fill(content);
+
+            if index == 0 {
+                wrapped[prefix_indent.len()..].to_string()
+            } else {
+                wrapped
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Determines whether stderr is a terminal that renders OSC-8 hyperlinks. As there is no way
+/// to query this, it is based on the environment variables set by terminals known to support
+/// them, and can be overridden by setting `FORCE_HYPERLINK` to `1` or `0`.
+fn supports_hyperlinks() -> bool {
+    if let Ok(force) = env::var("FORCE_HYPERLINK") {
+        return force != "0";
+    }
+
+    if !atty::is(Stream::Stderr) || env::var_os("CI").is_some() {
+        return false;
+    }
+
+    if ["WT_SESSION", "DOMTERM", "KONSOLE_VERSION"]
+        .iter()
+        .any(|var| env::var_os(var).is_some())
+    {
+        return true;
+    }
+
+    if let Ok(version) = env::var("VTE_VERSION") {
+        if version.parse::<u32>().map(|v| v >= 5000).unwrap_or(false) {
+            return true;
+        }
+    }
+
+    match env::var("TERM_PROGRAM") {
+        Ok(program) => ["iTerm.app", "WezTerm", "vscode", "Hyper"].contains(&program.as_str()),
+        Err(_) => false,
+    }
+}
+
+/// Renders the absolute file paths and URLs in a message as OSC-8 hyperlinks
+fn hyperlink_message(message: &str) -> String {
+    message
+        .split('\n')
+        .map(|line| {
+            line.split(' ')
+                .map(hyperlink_word)
+                .collect::<Vec<String>>()
+                .join(" ")
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn hyperlink_word(word: &str) -> String {
+    let leading = word.len() - word.trim_start_matches(LEADING_PUNCTUATION).len();
+    let text = word[leading..].trim_end_matches(TRAILING_PUNCTUATION);
+    let trailing = &word[leading + text.len()..];
+
+    match link_target(text) {
+        Some(target) => format!(
+            "{}\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\{}",
+            &word[..leading],
+            target,
+            text,
+            trailing
+        ),
+        None => word.to_string(),
+    }
+}
+
+/// Determines the URL to link to for a word of a message, if it is a URL or an absolute path
+fn link_target(text: &str) -> Option<String> {
+    if text.starts_with("https://") || text.starts_with("http://") {
+        return Some(text.to_string());
+    }
+
+    if text.is_empty() || !Path::new(text).is_absolute() {
+        return None;
+    }
+
+    cfg_if! {
+        if #[cfg(windows)] {
+            Some(format!("file:///{}", text.replace('\\', "/")))
+        } else {
+            Some(format!("file://{}", text))
+        }
+    }
+}
+
 /// Format the underlying cause of an error
 pub(crate) fn format_error_cause(inner: &Fail) -> String {
     format!(
@@ -45,6 +224,24 @@ pub fn text_width() -> Option<usize> {
     term_size::dimensions().map(|(w, _)| w.min(MAX_WIDTH))
 }
 
+/// Formats a number of bytes for display, e.g. `"12.3 MiB"`
+pub fn human_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
 /// Constructs a command-line progress bar based on the specified Origin enum
 /// (e.g., `Origin::Remote`), details string (e.g., `"v1.23.4"`), and logical
 /// length (i.e., the number of logical progress steps in the process being
@@ -112,3 +309,53 @@ cfg_if! {
         }
     }
 }
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_message() {
+        let message =
+            "Could not download node@12.4.0 from the registry\n\nPlease check your connection";
+
+        assert_eq!(
+            wrap_message(7, message, 30),
+            "Could not download
+    node@12.4.0 from the
+    registry
+
+Please check your connection"
+        );
+    }
+
+    #[test]
+    fn test_wrap_message_keeps_line_indentation() {
+        let message = "Invalid tool name\n\n    name cannot contain capital letters";
+
+        assert_eq!(
+            wrap_message(7, message, 30),
+            "Invalid tool name
+
+    name cannot contain
+        capital letters"
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_hyperlink_message() {
+        assert_eq!(
+            hyperlink_message("See https://volta.sh for details."),
+            "See \x1b]8;;https://volta.sh\x1b\\https://volta.sh\x1b]8;;\x1b\\ for details."
+        );
+        assert_eq!(
+            hyperlink_message("Could not read '/tmp/hooks.json'"),
+            "Could not read '\x1b]8;;file:///tmp/hooks.json\x1b\\/tmp/hooks.json\x1b]8;;\x1b\\'"
+        );
+        assert_eq!(
+            hyperlink_message("Use relative/path or node@12"),
+            "Use relative/path or node@12"
+        );
+    }
+}
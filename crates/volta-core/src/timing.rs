@@ -0,0 +1,204 @@
+//! Provides a lightweight timer for the major phases of a command, which `volta --timing`
+//! reports as a breakdown table when the command finishes.
+//!
+//! Timing is disabled by default, in which case a span costs a single atomic load. Phases
+//! may overlap: for example, resolving a version can include fetching a registry index, and
+//! a downloaded archive is streamed from the network while it is unpacked.
+
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use lazy_static::lazy_static;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+    static ref TIMINGS: Mutex<Timings> = Mutex::new(Timings {
+        start: Instant::now(),
+        records: Vec::new(),
+    });
+}
+
+struct Timings {
+    start: Instant,
+    records: Vec<(Phase, Duration)>,
+}
+
+/// The major phases of a Volta command.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    /// Finding and parsing the current project's `package.json`
+    ProjectDetection,
+    /// Resolving a version requirement to a specific version
+    Resolution,
+    /// Requests to registries and distribution servers
+    Network,
+    /// Unpacking archives into the image directory
+    Unpack,
+    /// Creating the shims for installed executables
+    ShimGeneration,
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Phase::ProjectDetection => "project detection",
+            Phase::Resolution => "resolution",
+            Phase::Network => "network",
+            Phase::Unpack => "unpack",
+            Phase::ShimGeneration => "shim generation",
+        })
+    }
+}
+
+/// Starts recording the durations of phases. The total reported time is measured from here.
+pub fn enable() {
+    if let Ok(mut timings) = TIMINGS.lock() {
+        timings.start = Instant::now();
+    }
+    ENABLED.store(true, Ordering::SeqCst);
+}
+
+/// A running timer for a phase, which records its duration when it is dropped.
+#[must_use]
+pub struct Span {
+    phase: Phase,
+    start: Option<Instant>,
+}
+
+/// Starts timing a phase, until the returned `Span` is dropped.
+pub fn span(phase: Phase) -> Span {
+    let start = if ENABLED.load(Ordering::Relaxed) {
+        Some(Instant::now())
+    } else {
+        None
+    };
+
+    Span { phase, start }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        if let Some(start) = self.start {
+            if let Ok(mut timings) = TIMINGS.lock() {
+                timings.records.push((self.phase, start.elapsed()));
+            }
+        }
+    }
+}
+
+/// The total time spent in a single phase.
+pub struct PhaseTiming {
+    pub phase: Phase,
+    /// The number of spans recorded for this phase
+    pub count: usize,
+    pub duration: Duration,
+}
+
+/// A breakdown of the time spent in each phase of a command.
+pub struct Report {
+    pub total: Duration,
+    pub phases: Vec<PhaseTiming>,
+}
+
+/// Produces the breakdown of the recorded phases, if timing is enabled.
+pub fn report() -> Option<Report> {
+    if !ENABLED.load(Ordering::SeqCst) {
+        return None;
+    }
+
+    let timings = TIMINGS.lock().ok()?;
+    Some(Report::new(timings.start.elapsed(), &timings.records))
+}
+
+impl Report {
+    fn new(total: Duration, records: &[(Phase, Duration)]) -> Self {
+        let mut phases: Vec<PhaseTiming> = Vec::new();
+        for &(phase, duration) in records {
+            match phases.iter_mut().find(|timing| timing.phase == phase) {
+                Some(timing) => {
+                    timing.count += 1;
+                    timing.duration += duration;
+                }
+                None => phases.push(PhaseTiming {
+                    phase,
+                    count: 1,
+                    duration,
+                }),
+            }
+        }
+        phases.sort_by_key(|timing| timing.phase);
+
+        Report { total, phases }
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs() as f64 * 1000.0 + f64::from(duration.subsec_micros()) / 1000.0
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let total = millis(self.total);
+
+        writeln!(
+            f,
+            "{:<20}{:>8}{:>14}{:>8}",
+            "phase", "count", "time", "share"
+        )?;
+        for timing in self.phases.iter() {
+            let time = millis(timing.duration);
+            let share = if total > 0.0 {
+                time / total * 100.0
+            } else {
+                0.0
+            };
+            writeln!(
+                f,
+                "{:<20}{:>8}{:>12.2}ms{:>7.1}%",
+                timing.phase.to_string(),
+                timing.count,
+                time,
+                share
+            )?;
+        }
+        write!(f, "{:<20}{:>8}{:>12.2}ms", "total", "", total)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_groups_phases() {
+        let records = vec![
+            (Phase::Network, Duration::from_millis(30)),
+            (Phase::ProjectDetection, Duration::from_millis(2)),
+            (Phase::Network, Duration::from_millis(10)),
+        ];
+        let report = Report::new(Duration::from_millis(100), &records);
+
+        assert_eq!(report.phases.len(), 2);
+        assert_eq!(report.phases[0].phase, Phase::ProjectDetection);
+        assert_eq!(report.phases[0].count, 1);
+        assert_eq!(report.phases[1].phase, Phase::Network);
+        assert_eq!(report.phases[1].count, 2);
+        assert_eq!(report.phases[1].duration, Duration::from_millis(40));
+    }
+
+    #[test]
+    fn test_report_display() {
+        let records = vec![(Phase::Unpack, Duration::from_millis(25))];
+        let report = Report::new(Duration::from_millis(100), &records);
+
+        assert_eq!(
+            report.to_string(),
+            "phase                  count          time   share
+unpack                     1       25.00ms   25.0%
+total                             100.00ms"
+        );
+    }
+}
@@ -1,12 +1,18 @@
 use std::fmt::{self, Display};
+use std::fs::{File, OpenOptions};
+use std::io::{self, Seek, SeekFrom};
+use std::path::Path;
 
 use crate::error::ErrorDetails;
+use crate::hook::tool::Location;
 use crate::session::Session;
 use crate::style::{success_prefix, tool_version};
+use crate::timing::{self, Phase};
 use crate::version::VersionSpec;
 use log::{debug, info};
 use semver::Version;
-use volta_fail::Fallible;
+use sha1::{Digest, Sha1};
+use volta_fail::{throw, Fallible, ResultExt};
 
 mod node;
 mod npm;
@@ -14,8 +20,8 @@ mod package;
 mod serial;
 mod yarn;
 
-pub use node::{load_default_npm_version, Node, NodeVersion};
-pub use npm::Npm;
+pub use node::{import_external_node, load_default_npm_version, Node, NodeVersion};
+pub use npm::{resolve_npm_overlay, Npm};
 pub use package::{bin_full_path, BinConfig, BinLoader, Package, PackageConfig, PackageDetails};
 pub use yarn::Yarn;
 
@@ -50,7 +56,7 @@ pub trait Tool: Display {
 }
 
 /// Specification for a tool and its associated version.
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum Spec {
     Node(VersionSpec),
     Npm(VersionSpec),
@@ -70,6 +76,7 @@ pub enum Resolved {
 impl Spec {
     /// Resolve a tool spec into a fully realized Tool that can be fetched
     pub fn resolve(self, session: &mut Session) -> Fallible<Resolved> {
+        let _span = timing::span(Phase::Resolution);
         match self {
             Spec::Node(version) => node::resolve(version, session)
                 .map(Node::new)
@@ -89,6 +96,19 @@ impl Spec {
         }
     }
 
+    /// Resolve a tool spec, letting the user choose among the matching versions
+    ///
+    /// Only Node has an index with enough detail to choose from, so the other tools are
+    /// resolved as usual.
+    pub fn resolve_interactive(self, session: &mut Session) -> Fallible<Resolved> {
+        match self {
+            Spec::Node(version) => node::pick(version, session)
+                .map(Node::new)
+                .map(Resolved::Node),
+            spec => spec.resolve(session),
+        }
+    }
+
     /// Uninstall a tool, removing it from the local inventory
     ///
     /// This is implemented on Spec, instead of Resolved, because there is currently no need to
@@ -137,6 +157,16 @@ impl Resolved {
         }
     }
 
+    /// Expose the executables of a package's direct dependencies as well, when it is installed
+    ///
+    /// This has no effect on Node, npm, or Yarn.
+    pub fn with_dependencies_bins(self) -> Self {
+        match self {
+            Resolved::Package(package) => Resolved::Package(package.with_dependencies_bins()),
+            tool => tool,
+        }
+    }
+
     /// Pin a tool in the local project so that it is usable within the project
     pub fn pin(self, session: &mut Session) -> Fallible<()> {
         match self {
@@ -171,6 +201,51 @@ impl From<Resolved> for Version {
     }
 }
 
+/// Downloads a distro from a hook location to the staging path, and verifies it against the
+/// checksum provided by the hook before it is unpacked.
+fn fetch_verified_distro(
+    tool: Spec,
+    location: &Location,
+    checksum: &str,
+    staging_path: &Path,
+) -> Fallible<File> {
+    let download_error = || ErrorDetails::DownloadToolNetworkError {
+        tool: tool.clone(),
+        from_url: location.url.clone(),
+    };
+
+    let mut distro = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .truncate(true)
+        .open(staging_path)
+        .with_context(|_| download_error())?;
+    location
+        .get()
+        .and_then(|response| response.error_for_status())
+        .and_then(|mut response| response.copy_to(&mut distro))
+        .with_context(|_| download_error())?;
+
+    distro
+        .seek(SeekFrom::Start(0))
+        .with_context(|_| download_error())?;
+    let mut hasher = Sha1::new();
+    io::copy(&mut distro, &mut hasher).with_context(|_| download_error())?;
+    let calculated_checksum = hex::encode(&hasher.result());
+
+    if !calculated_checksum.eq_ignore_ascii_case(checksum.trim()) {
+        throw!(ErrorDetails::DownloadChecksumMismatch {
+            tool,
+            from_url: location.url.clone(),
+        });
+    }
+
+    distro
+        .seek(SeekFrom::Start(0))
+        .with_context(|_| download_error())?;
+    Ok(distro)
+}
+
 fn download_tool_error(
     tool: Spec,
     from_url: impl AsRef<str>,
@@ -3,12 +3,15 @@
 use std::fs::{read_to_string, rename, write, File};
 use std::path::Path;
 
-use super::super::download_tool_error;
+use super::super::{download_tool_error, fetch_verified_distro};
 use crate::error::ErrorDetails;
 use crate::fs::{create_staging_dir, create_staging_file};
+use crate::hook::tool::Location;
 use crate::hook::ToolHooks;
 use crate::path;
+use crate::permissions;
 use crate::style::{progress_bar, tool_version};
+use crate::timing::{self, Phase};
 use crate::tool::{self, Node, NodeVersion};
 use crate::version::VersionSpec;
 use archive::{self, Archive};
@@ -34,6 +37,10 @@ cfg_if! {
 pub fn fetch(version: &Version, hooks: Option<&ToolHooks<Node>>) -> Fallible<NodeVersion> {
     let node_dir = path::node_inventory_dir()?;
     let cache_file = node_dir.join(path::node_distro_file_name(&version.to_string()));
+    // The image directory also depends on the bundled npm version, which is only known once
+    // the archive is unpacked
+    permissions::check_path(&cache_file)?;
+    permissions::check_path(&path::node_image_root_dir()?.join(version.to_string()))?;
 
     let (archive, staging) = match load_cached_distro(&cache_file) {
         Some(archive) => {
@@ -46,8 +53,8 @@ pub fn fetch(version: &Version, hooks: Option<&ToolHooks<Node>>) -> Fallible<Nod
         }
         None => {
             let staging = create_staging_file()?;
-            let remote_url = determine_remote_url(&version, hooks)?;
-            let archive = fetch_remote_distro(&version, &remote_url, staging.path())?;
+            let location = determine_remote_url(&version, hooks)?;
+            let archive = fetch_remote_distro(&version, &location, staging.path())?;
             (archive, Some(staging))
         }
     };
@@ -72,6 +79,7 @@ pub fn fetch(version: &Version, hooks: Option<&ToolHooks<Node>>) -> Fallible<Nod
 
 /// Unpack the node archive into the image directory so that it is ready for use
 fn unpack_archive(archive: Box<Archive>, version: &Version) -> Fallible<NodeVersion> {
+    let _span = timing::span(Phase::Unpack);
     let temp = create_staging_dir()?;
     debug!("Unpacking node into '{}'", temp.path().display());
 
@@ -140,7 +148,7 @@ fn load_cached_distro(file: &Path) -> Option<Box<dyn Archive>> {
 }
 
 /// Determine the remote URL to download from, using the hooks if available
-fn determine_remote_url(version: &Version, hooks: Option<&ToolHooks<Node>>) -> Fallible<String> {
+fn determine_remote_url(version: &Version, hooks: Option<&ToolHooks<Node>>) -> Fallible<Location> {
     let version_str = version.to_string();
     let distro_file_name = path::node_distro_file_name(&version_str);
     match hooks {
@@ -149,39 +157,48 @@ fn determine_remote_url(version: &Version, hooks: Option<&ToolHooks<Node>>) -> F
             ..
         }) => {
             debug!("Using node.distro hook to determine download URL");
-            hook.resolve(&version, &distro_file_name)
+            hook.resolve("node", &version, &distro_file_name)
         }
         _ => Ok(format!(
             "{}/v{}/{}",
             public_node_server_root(),
             version,
             distro_file_name
-        )),
+        )
+        .into()),
     }
 }
 
 /// Fetch the distro archive from the internet
 fn fetch_remote_distro(
     version: &Version,
-    url: &str,
+    location: &Location,
     staging_path: &Path,
 ) -> Fallible<Box<Archive>> {
+    let _span = timing::span(Phase::Network);
+    let url = &location.url;
+    let spec = tool::Spec::Node(VersionSpec::exact(&version));
     debug!("Downloading {} from {}", tool_version("node", version), url);
-    archive::fetch_native(url, staging_path).with_context(download_tool_error(
-        tool::Spec::Node(VersionSpec::exact(&version)),
-        url,
-    ))
+
+    match location.checksum {
+        Some(ref checksum) => {
+            let distro = fetch_verified_distro(spec.clone(), location, checksum, staging_path)?;
+            archive::load_native(distro).with_context(download_tool_error(spec, url))
+        }
+        None => archive::fetch_native_with_headers(url, &location.headers, staging_path)
+            .with_context(download_tool_error(spec, url)),
+    }
 }
 
 /// The portion of npm's `package.json` file that we care about
 #[derive(Deserialize)]
-struct Manifest {
+pub(super) struct Manifest {
     version: String,
 }
 
 impl Manifest {
     /// Parse the version out of a package.json file
-    fn version(path: &Path) -> Fallible<Version> {
+    pub(super) fn version(path: &Path) -> Fallible<Version> {
         let file = File::open(path).with_context(|_| ErrorDetails::ReadNpmManifestError)?;
         let manifest: Manifest = serde_json::de::from_reader(file)
             .with_context(|_| ErrorDetails::ParseNpmManifestError)?;
@@ -201,7 +218,7 @@ pub fn load_default_npm_version(node: &Version) -> Fallible<Version> {
 }
 
 /// Save the default npm version to the filesystem for a given version of Node
-fn save_default_npm_version(node: &Version, npm: &Version) -> Fallible<()> {
+pub(super) fn save_default_npm_version(node: &Version, npm: &Version) -> Fallible<()> {
     let npm_version_file_path = path::node_npm_version_file(&node.to_string())?;
     write(&npm_version_file_path, npm.to_string().as_bytes()).with_context(|_| {
         ErrorDetails::WriteDefaultNpmError {
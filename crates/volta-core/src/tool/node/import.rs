@@ -0,0 +1,96 @@
+//! Provides importing of Node installations from outside of Volta, such as a vendor-provided
+//! Node in `/opt`. An imported installation is linked into the image directory instead of
+//! being copied, so it can be pinned and used in the default platform like a fetched version.
+
+use std::fs;
+use std::path::Path;
+
+use super::fetch::{load_default_npm_version, save_default_npm_version, Manifest};
+use super::NodeVersion;
+use crate::command::create_command;
+use crate::error::ErrorDetails;
+use crate::path;
+use crate::style::tool_version;
+use fs_utils::ensure_containing_dir_exists;
+use log::debug;
+use semver::Version;
+use volta_fail::{throw, Fallible, ResultExt};
+
+/// Imports the Node installation in the given directory, after validating its layout and
+/// determining the versions of Node and the bundled npm.
+pub fn import(dir: &Path) -> Fallible<NodeVersion> {
+    let not_found = || ErrorDetails::ExternalNodeNotFound {
+        path: dir.to_path_buf(),
+    };
+    let root = dir.canonicalize().with_context(|_| not_found())?;
+    let executable = path::node_install_executable(&root);
+    let npm_package_json = path::node_install_npm_package_json_path(&root);
+    if !executable.is_file() || !npm_package_json.is_file() {
+        throw!(not_found());
+    }
+
+    let runtime = external_version(&executable, &root)?;
+    let npm = Manifest::version(&npm_package_json)?;
+    let runtime_str = runtime.to_string();
+
+    // An imported installation can't replace a version that Volta has already fetched
+    let version_file = path::node_npm_version_file(&runtime_str)?;
+    if version_file.exists() || path::node_image_root_dir()?.join(&runtime_str).exists() {
+        throw!(ErrorDetails::NodeImageExists {
+            version: tool_version("node", &runtime),
+        });
+    }
+
+    let image_dir = path::node_image_dir(&runtime_str, &npm.to_string())?;
+    debug!(
+        "Linking external node installation '{}' to '{}'",
+        root.display(),
+        image_dir.display()
+    );
+    for file in [&image_dir, &version_file].iter() {
+        ensure_containing_dir_exists(file).with_context(|_| ErrorDetails::ContainingDirError {
+            path: file.to_path_buf(),
+        })?;
+    }
+    path::create_dir_symlink(root, image_dir.clone()).with_context(|_| {
+        ErrorDetails::SetupToolImageError {
+            tool: "Node".into(),
+            version: runtime_str,
+            dir: image_dir,
+        }
+    })?;
+    save_default_npm_version(&runtime, &npm)?;
+
+    Ok(NodeVersion { runtime, npm })
+}
+
+/// Determines whether a Node version is an imported installation, whose image is a link
+pub(super) fn is_imported(version: &Version) -> bool {
+    let image_dir = load_default_npm_version(version)
+        .and_then(|npm| path::node_image_dir(&version.to_string(), &npm.to_string()));
+
+    match image_dir {
+        Ok(dir) => fs::symlink_metadata(dir)
+            .map(|metadata| metadata.file_type().is_symlink())
+            .unwrap_or(false),
+        Err(_) => false,
+    }
+}
+
+/// Runs `node --version` for an external installation
+fn external_version(executable: &Path, root: &Path) -> Fallible<Version> {
+    let version_error = || ErrorDetails::ExternalNodeVersionError {
+        path: root.to_path_buf(),
+    };
+
+    let output = create_command(executable)
+        .arg("--version")
+        .output()
+        .with_context(|_| version_error())?;
+    if !output.status.success() {
+        throw!(version_error());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Version::parse(stdout.trim().trim_start_matches('v')).with_context(|_| version_error())
+}
@@ -8,12 +8,23 @@ use semver::Version;
 use volta_fail::Fallible;
 
 mod fetch;
+mod import;
+mod pick;
 mod resolve;
 mod serial;
 
 pub use fetch::load_default_npm_version;
+pub use import::import as import_external_node;
+pub use pick::pick;
 pub use resolve::resolve;
 
+/// Determines the version of npm bundled with a Node version. That is only recorded once the
+/// Node version has been fetched, so it is fetched first if necessary.
+pub(crate) fn bundled_npm_version(node: &Version, session: &mut Session) -> Fallible<Version> {
+    session.ensure_node(node)?;
+    load_default_npm_version(node)
+}
+
 /// A full Node version including not just the version of Node itself
 /// but also the specific version of npm installed globally with that
 /// Node installation.
@@ -49,7 +60,7 @@ impl Node {
 
     pub(crate) fn fetch_internal(&self, session: &mut Session) -> Fallible<NodeVersion> {
         let inventory = session.inventory()?;
-        if inventory.node.versions.contains(&self.version) {
+        if inventory.node.versions.contains(&self.version) || import::is_imported(&self.version) {
             debug_already_fetched(self);
             let npm = fetch::load_default_npm_version(&self.version)?;
 
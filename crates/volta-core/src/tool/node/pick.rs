@@ -0,0 +1,245 @@
+//! Provides an interactive picker for choosing among the Node versions that match a
+//! requirement, instead of always using the newest one.
+
+use std::io::{self, BufRead, Write};
+
+use super::resolve::{matching_entries, resolve, NodeEntry};
+use crate::error::ErrorDetails;
+use crate::session::Session;
+use crate::version::VersionSpec;
+use atty::Stream;
+use log::debug;
+use semver::Version;
+use volta_fail::{throw, Fallible, ResultExt};
+
+/// The most versions shown at once, since a broad requirement can match hundreds.
+const MAX_CHOICES: usize = 20;
+
+/// Lets the user choose a Node version matching the spec from a list of the matching versions
+/// in the index. When Volta is not running in a terminal, this resolves the spec as usual.
+pub fn pick(matching: VersionSpec, session: &mut Session) -> Fallible<Version> {
+    if !atty::is(Stream::Stdin) || !atty::is(Stream::Stderr) {
+        debug!(
+            "Not running in a terminal, resolving node@{} as usual",
+            matching
+        );
+        return resolve(matching, session);
+    }
+
+    let entries = match &matching {
+        VersionSpec::Exact(version) => return Ok(version.clone()),
+        VersionSpec::Latest => matching_entries(session, |_| true)?,
+        VersionSpec::Lts => matching_entries(session, |entry| entry.lts)?,
+        VersionSpec::Semver(requirement) => {
+            matching_entries(session, |entry| requirement.matches(&entry.version))?
+        }
+    };
+
+    match entries.len() {
+        0 => throw!(ErrorDetails::NodeVersionNotFound {
+            matching: matching.to_string(),
+        }),
+        1 => return Ok(entries[0].version.clone()),
+        _ => {}
+    }
+
+    let stdin = io::stdin();
+    select(&entries, stdin.lock(), io::stderr())
+}
+
+/// Shows the newest entries as numbered choices and reads the user's selection from the input,
+/// prompting again until it is valid. Besides a number, the user can type any matching version,
+/// including those not shown.
+fn select<R, W>(entries: &[NodeEntry], mut input: R, mut output: W) -> Fallible<Version>
+where
+    R: BufRead,
+    W: Write,
+{
+    let shown = entries.len().min(MAX_CHOICES);
+    writeln!(output, "{}", format_choices(&entries[..shown]))
+        .with_context(|_| ErrorDetails::NoVersionSelected)?;
+    if entries.len() > shown {
+        writeln!(
+            output,
+            "Showing the newest {} of {} matching versions, type a version to select another",
+            shown,
+            entries.len()
+        )
+        .with_context(|_| ErrorDetails::NoVersionSelected)?;
+    }
+
+    loop {
+        write!(output, "Select a version [1]: ")
+            .and_then(|_| output.flush())
+            .with_context(|_| ErrorDetails::NoVersionSelected)?;
+
+        let mut line = String::new();
+        let read = input
+            .read_line(&mut line)
+            .with_context(|_| ErrorDetails::NoVersionSelected)?;
+        if read == 0 {
+            throw!(ErrorDetails::NoVersionSelected);
+        }
+
+        match parse_selection(&line, entries, shown) {
+            Some(index) => return Ok(entries[index].version.clone()),
+            None => writeln!(
+                output,
+                "Please enter a number from 1 to {} or a matching version",
+                shown
+            )
+            .with_context(|_| ErrorDetails::NoVersionSelected)?,
+        }
+    }
+}
+
+/// Formats the choices as a numbered table, with the npm version, release date, and LTS
+/// status of each.
+fn format_choices(choices: &[NodeEntry]) -> String {
+    let mut table = format!("     {:<12}{:<10}{:<12}{}", "version", "npm", "date", "lts");
+    for (index, entry) in choices.iter().enumerate() {
+        let row = format!(
+            "{:>3}) {:<12}{:<10}{:<12}{}",
+            index + 1,
+            entry.version.to_string(),
+            entry.npm.to_string(),
+            entry.date.as_ref().map(String::as_str).unwrap_or(""),
+            if entry.lts { "LTS" } else { "" }
+        );
+        table.push('\n');
+        table.push_str(row.trim_end());
+    }
+    table
+}
+
+/// Parses what the user entered into an index of the entries: either the number of one of the
+/// first `shown` choices, or a version from any of the entries. An empty entry selects the first,
+/// newest version, as resolving without the picker would.
+fn parse_selection(input: &str, entries: &[NodeEntry], shown: usize) -> Option<usize> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Some(0);
+    }
+
+    if let Ok(number) = input.parse::<usize>() {
+        return if number >= 1 && number <= shown {
+            Some(number - 1)
+        } else {
+            None
+        };
+    }
+
+    let version = Version::parse(input.trim_start_matches('v')).ok()?;
+    entries.iter().position(|entry| entry.version == version)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::super::resolve::NodeDistroFiles;
+    use super::*;
+    use std::collections::HashSet;
+    use std::io::Cursor;
+
+    fn entry(version: &str, npm: &str, date: &str, lts: bool) -> NodeEntry {
+        NodeEntry {
+            version: Version::parse(version).unwrap(),
+            npm: Version::parse(npm).unwrap(),
+            files: NodeDistroFiles {
+                files: HashSet::new(),
+            },
+            date: Some(date.to_string()),
+            lts,
+        }
+    }
+
+    #[test]
+    fn test_format_choices() {
+        let choices = vec![
+            entry("12.4.0", "6.9.0", "2019-06-04", false),
+            entry("10.16.0", "6.9.0", "2019-05-28", true),
+        ];
+
+        assert_eq!(
+            format_choices(&choices),
+            "     version     npm       date        lts
+  1) 12.4.0      6.9.0     2019-06-04
+  2) 10.16.0     6.9.0     2019-05-28  LTS"
+        );
+    }
+
+    #[test]
+    fn test_parse_selection() {
+        let entries = vec![
+            entry("12.4.0", "6.9.0", "2019-06-04", false),
+            entry("12.3.1", "6.9.0", "2019-05-22", false),
+            entry("10.16.0", "6.9.0", "2019-05-28", true),
+        ];
+
+        assert_eq!(parse_selection("\n", &entries, 2), Some(0));
+        assert_eq!(parse_selection("2\n", &entries, 2), Some(1));
+        assert_eq!(parse_selection(" 1 ", &entries, 2), Some(0));
+        assert_eq!(parse_selection("0", &entries, 2), None);
+        assert_eq!(parse_selection("3", &entries, 2), None);
+        assert_eq!(parse_selection("12.3.1", &entries, 2), Some(1));
+        assert_eq!(parse_selection("v10.16.0", &entries, 2), Some(2));
+        assert_eq!(parse_selection("11.0.0", &entries, 2), None);
+        assert_eq!(parse_selection("latest", &entries, 2), None);
+    }
+
+    fn many_entries(count: u64) -> Vec<NodeEntry> {
+        (0..count)
+            .rev()
+            .map(|minor| entry(&format!("12.{}.0", minor), "6.9.0", "2019-06-04", false))
+            .collect()
+    }
+
+    #[test]
+    fn test_select_default() {
+        let entries = many_entries(3);
+        let mut output = Vec::new();
+
+        let version = select(&entries, Cursor::new("\n"), &mut output).unwrap();
+
+        assert_eq!(version, Version::parse("12.2.0").unwrap());
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .ends_with("Select a version [1]: "));
+    }
+
+    #[test]
+    fn test_select_retries_invalid_input() {
+        let entries = many_entries(3);
+        let mut output = Vec::new();
+
+        let version = select(&entries, Cursor::new("7\nnope\n3\n"), &mut output).unwrap();
+
+        assert_eq!(version, Version::parse("12.0.0").unwrap());
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(
+            output
+                .matches("Please enter a number from 1 to 3 or a matching version")
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_select_version_not_shown() {
+        let entries = many_entries(MAX_CHOICES as u64 + 5);
+        let mut output = Vec::new();
+
+        let version = select(&entries, Cursor::new("12.2.0\n"), &mut output).unwrap();
+
+        assert_eq!(version, Version::parse("12.2.0").unwrap());
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Showing the newest 20 of 25 matching versions"));
+        assert!(!output.contains(" 12.2.0 "));
+    }
+
+    #[test]
+    fn test_select_end_of_input() {
+        let entries = many_entries(3);
+
+        assert!(select(&entries, Cursor::new(""), Vec::new()).is_err());
+    }
+}
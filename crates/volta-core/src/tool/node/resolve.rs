@@ -10,10 +10,12 @@ use super::super::registry_fetch_error;
 use super::serial;
 use crate::error::ErrorDetails;
 use crate::fs::{create_staging_file, read_file};
+use crate::hook::tool::Location;
 use crate::hook::ToolHooks;
 use crate::path;
 use crate::session::Session;
 use crate::style::progress_spinner;
+use crate::timing::{self, Phase};
 use crate::tool::Node;
 use crate::version::VersionSpec;
 use cfg_if::cfg_if;
@@ -53,21 +55,24 @@ fn resolve_latest(hooks: Option<&ToolHooks<Node>>) -> Fallible<Version> {
     // NOTE: This assumes the registry always produces a list in sorted order
     //       from newest to oldest. This should be specified as a requirement
     //       when we document the plugin API.
-    let url = match hooks {
+    let location = match hooks {
         Some(&ToolHooks {
             latest: Some(ref hook),
             ..
         }) => {
             debug!("Using node.latest hook to determine node index URL");
-            hook.resolve("index.json")?
+            hook.resolve("node", "index.json")?
         }
-        _ => public_node_version_index(),
+        _ => public_node_version_index().into(),
     };
-    let version_opt = match_node_version(&url, |_| true)?;
+    let version_opt = match_node_version(&location, |_| true)?;
 
     match version_opt {
         Some(version) => {
-            debug!("Found latest node version ({}) from {}", version, url);
+            debug!(
+                "Found latest node version ({}) from {}",
+                version, location.url
+            );
             Ok(version)
         }
         None => Err(ErrorDetails::NodeVersionNotFound {
@@ -78,21 +83,15 @@ fn resolve_latest(hooks: Option<&ToolHooks<Node>>) -> Fallible<Version> {
 }
 
 fn resolve_lts(hooks: Option<&ToolHooks<Node>>) -> Fallible<Version> {
-    let url = match hooks {
-        Some(&ToolHooks {
-            index: Some(ref hook),
-            ..
-        }) => {
-            debug!("Using node.index hook to determine node index URL");
-            hook.resolve("index.json")?
-        }
-        _ => public_node_version_index(),
-    };
-    let version_opt = match_node_version(&url, |&NodeEntry { lts, .. }| lts)?;
+    let location = index_location(hooks)?;
+    let version_opt = match_node_version(&location, |&NodeEntry { lts, .. }| lts)?;
 
     match version_opt {
         Some(version) => {
-            debug!("Found newest LTS node version ({}) from {}", version, url);
+            debug!(
+                "Found newest LTS node version ({}) from {}",
+                version, location.url
+            );
             Ok(version)
         }
         None => Err(ErrorDetails::NodeVersionNotFound {
@@ -104,24 +103,16 @@ fn resolve_lts(hooks: Option<&ToolHooks<Node>>) -> Fallible<Version> {
 
 fn resolve_semver(matching: VersionReq, hooks: Option<&ToolHooks<Node>>) -> Fallible<Version> {
     // ISSUE #34: also make sure this OS is available for this version
-    let url = match hooks {
-        Some(&ToolHooks {
-            index: Some(ref hook),
-            ..
-        }) => {
-            debug!("Using node.index hook to determine node index URL");
-            hook.resolve("index.json")?
-        }
-        _ => public_node_version_index(),
-    };
-    let version_opt =
-        match_node_version(&url, |NodeEntry { version, .. }| matching.matches(version))?;
+    let location = index_location(hooks)?;
+    let version_opt = match_node_version(&location, |NodeEntry { version, .. }| {
+        matching.matches(version)
+    })?;
 
     match version_opt {
         Some(version) => {
             debug!(
                 "Found node@{} matching requirement '{}' from {}",
-                version, matching, url
+                version, matching, location.url
             );
             Ok(version)
         }
@@ -132,11 +123,35 @@ fn resolve_semver(matching: VersionReq, hooks: Option<&ToolHooks<Node>>) -> Fall
     }
 }
 
+/// Lists the entries of the Node index that match a predicate, from newest to oldest.
+pub(super) fn matching_entries(
+    session: &Session,
+    predicate: impl Fn(&NodeEntry) -> bool,
+) -> Fallible<Vec<NodeEntry>> {
+    let location = index_location(session.hooks()?.node())?;
+    let index: NodeIndex = resolve_node_versions(&location)?.into();
+    Ok(index.entries.into_iter().filter(predicate).collect())
+}
+
+/// Determines the location of the Node index, from the `node.index` hook if there is one.
+fn index_location(hooks: Option<&ToolHooks<Node>>) -> Fallible<Location> {
+    match hooks {
+        Some(&ToolHooks {
+            index: Some(ref hook),
+            ..
+        }) => {
+            debug!("Using node.index hook to determine node index URL");
+            hook.resolve("node", "index.json")
+        }
+        _ => Ok(public_node_version_index().into()),
+    }
+}
+
 fn match_node_version(
-    url: &str,
+    location: &Location,
     predicate: impl Fn(&NodeEntry) -> bool,
 ) -> Fallible<Option<Version>> {
-    let index: NodeIndex = resolve_node_versions(url)?.into();
+    let index: NodeIndex = resolve_node_versions(location)?.into();
     let mut entries = index.entries.into_iter();
     Ok(entries
         .find(predicate)
@@ -153,6 +168,8 @@ pub struct NodeEntry {
     pub version: Version,
     pub npm: Version,
     pub files: NodeDistroFiles,
+    /// The release date, like `2019-06-04`
+    pub date: Option<String>,
     pub lts: bool,
 }
 
@@ -202,14 +219,17 @@ fn max_age(response: &reqwest::Response) -> u32 {
     4 * 60 * 60
 }
 
-fn resolve_node_versions(url: &str) -> Fallible<serial::RawNodeIndex> {
+fn resolve_node_versions(location: &Location) -> Fallible<serial::RawNodeIndex> {
+    let url = location.url.as_str();
     match read_cached_opt()? {
         Some(serial) => Ok(serial),
         None => {
+            let _span = timing::span(Phase::Network);
             let spinner = progress_spinner(&format!("Fetching public registry: {}", url));
 
-            let mut response: reqwest::Response =
-                reqwest::get(url).with_context(registry_fetch_error("Node", url))?;
+            let mut response: reqwest::Response = location
+                .get()
+                .with_context(registry_fetch_error("Node", url))?;
             let response_text = response
                 .text()
                 .with_context(registry_fetch_error("Node", url))?;
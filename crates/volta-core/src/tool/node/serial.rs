@@ -17,6 +17,8 @@ pub struct RawNodeEntry {
     #[serde(with = "option_version_serde")]
     pub npm: Option<Version>,
     pub files: Vec<String>,
+    #[serde(default)]
+    pub date: Option<String>,
     #[serde(deserialize_with = "lts_version_serde")]
     pub lts: bool,
 }
@@ -33,6 +35,7 @@ impl From<RawNodeIndex> for NodeIndex {
                     version: entry.version,
                     npm,
                     files: data,
+                    date: entry.date,
                     lts: entry.lts,
                 });
             }
@@ -0,0 +1,216 @@
+//! Provides fetcher for npm packages from the npm registry
+
+use std::fs::{rename, File};
+use std::path::Path;
+
+use super::super::download_tool_error;
+use crate::error::ErrorDetails;
+use crate::fs::{create_staging_dir, create_staging_file};
+use crate::path;
+use crate::permissions;
+use crate::style::{progress_bar, tool_version};
+use crate::timing::{self, Phase};
+use crate::tool;
+use crate::version::VersionSpec;
+use archive::{Archive, Tarball};
+use cfg_if::cfg_if;
+use fs_utils::ensure_containing_dir_exists;
+use log::debug;
+use semver::Version;
+use volta_fail::{Fallible, ResultExt};
+
+cfg_if! {
+    if #[cfg(feature = "mock-network")] {
+        fn public_npm_server_root() -> String {
+            format!("{}/npm/-", mockito::SERVER_URL)
+        }
+    } else {
+        fn public_npm_server_root() -> String {
+            "https://registry.npmjs.org/npm/-".to_string()
+        }
+    }
+}
+
+pub fn fetch(version: &Version) -> Fallible<()> {
+    let npm_dir = path::npm_inventory_dir()?;
+    let cache_file = npm_dir.join(path::npm_distro_file_name(&version.to_string()));
+    permissions::check_path(&cache_file)?;
+    permissions::check_path(&path::npm_image_dir(&version.to_string())?)?;
+
+    let (archive, staging) = match load_cached_distro(&cache_file) {
+        Some(archive) => {
+            debug!(
+                "Loading {} from cached archive at '{}'",
+                tool_version("npm", &version),
+                cache_file.display(),
+            );
+            (archive, None)
+        }
+        None => {
+            let staging = create_staging_file()?;
+            let remote_url = determine_remote_url(&version);
+            let archive = fetch_remote_distro(&version, &remote_url, staging.path())?;
+            (archive, Some(staging))
+        }
+    };
+
+    unpack_archive(archive, version)?;
+
+    if let Some(staging_file) = staging {
+        ensure_containing_dir_exists(&cache_file).with_context(|_| {
+            ErrorDetails::ContainingDirError {
+                path: cache_file.clone(),
+            }
+        })?;
+        staging_file
+            .persist(cache_file)
+            .with_context(|_| ErrorDetails::PersistInventoryError { tool: "npm".into() })?;
+    }
+
+    Ok(())
+}
+
+/// Unpack the npm archive into the image directory so that it is ready to be overlaid on a
+/// Node image
+fn unpack_archive(archive: Box<Archive>, version: &Version) -> Fallible<()> {
+    let _span = timing::span(Phase::Unpack);
+    let temp = create_staging_dir()?;
+    debug!("Unpacking npm into '{}'", temp.path().display());
+
+    let bar = progress_bar(
+        archive.origin(),
+        &tool_version("npm", version),
+        archive
+            .uncompressed_size()
+            .unwrap_or(archive.compressed_size()),
+    );
+    let version_string = version.to_string();
+
+    archive
+        .unpack(temp.path(), &mut |_, read| {
+            bar.inc(read as u64);
+        })
+        .with_context(|_| ErrorDetails::UnpackArchiveError {
+            tool: "npm".into(),
+            version: version_string.clone(),
+        })?;
+
+    // The launchers shipped in the npm package expect to live inside a Node installation,
+    // so replace them with ones that run the npm CLI from the image directory.
+    let unpacked = temp.path().join(path::npm_archive_root_dir_name());
+    create_launchers(&unpacked.join("bin")).with_context(|_| {
+        ErrorDetails::SetupToolImageError {
+            tool: "npm".into(),
+            version: version_string.clone(),
+            dir: unpacked.clone(),
+        }
+    })?;
+
+    let dest = path::npm_image_dir(&version_string)?;
+    ensure_containing_dir_exists(&dest)
+        .with_context(|_| ErrorDetails::ContainingDirError { path: dest.clone() })?;
+
+    rename(&unpacked, &dest).with_context(|_| ErrorDetails::SetupToolImageError {
+        tool: "npm".into(),
+        version: version_string.clone(),
+        dir: dest.clone(),
+    })?;
+
+    bar.finish_and_clear();
+
+    // Note: We write this after the progress bar is finished to avoid display bugs with re-renders of the progress
+    debug!("Installing npm in '{}'", dest.display());
+
+    Ok(())
+}
+
+#[cfg(unix)]
+fn create_launchers(bin_dir: &Path) -> std::io::Result<()> {
+    use std::fs::{self, Permissions};
+    use std::os::unix::fs::PermissionsExt;
+
+    for &(launcher, cli) in &[("npm", "npm-cli.js"), ("npx", "npx-cli.js")] {
+        let launcher = bin_dir.join(launcher);
+        if launcher.exists() {
+            fs::remove_file(&launcher)?;
+        }
+        path::create_file_symlink(Path::new(cli).to_path_buf(), launcher)?;
+        // Don't rely on the mode stored in the tarball for the scripts the launchers run
+        fs::set_permissions(bin_dir.join(cli), Permissions::from_mode(0o755))?;
+    }
+    Ok(())
+}
+
+#[cfg(windows)]
+fn create_launchers(bin_dir: &Path) -> std::io::Result<()> {
+    for &(launcher, cli) in &[("npm.cmd", "npm-cli.js"), ("npx.cmd", "npx-cli.js")] {
+        std::fs::write(
+            bin_dir.join(launcher),
+            format!("@ECHO OFF\r\nnode \"%~dp0\\{}\" %*\r\n", cli),
+        )?;
+    }
+    Ok(())
+}
+
+/// Return the archive if it is valid. It may have been corrupted or interrupted in the middle of
+/// downloading.
+fn load_cached_distro(file: &Path) -> Option<Box<Archive>> {
+    if file.is_file() {
+        let file = File::open(file).ok()?;
+        Tarball::load(file).ok()
+    } else {
+        None
+    }
+}
+
+/// Determine the remote URL to download from
+fn determine_remote_url(version: &Version) -> String {
+    format!(
+        "{}/{}",
+        public_npm_server_root(),
+        path::npm_distro_file_name(&version.to_string())
+    )
+}
+
+/// Fetch the distro archive from the internet
+fn fetch_remote_distro(
+    version: &Version,
+    url: &str,
+    staging_path: &Path,
+) -> Fallible<Box<Archive>> {
+    let _span = timing::span(Phase::Network);
+    debug!("Downloading {} from {}", tool_version("npm", version), url);
+    Tarball::fetch(url, staging_path).with_context(download_tool_error(
+        tool::Spec::Npm(VersionSpec::exact(&version)),
+        url,
+    ))
+}
+
+#[cfg(all(test, unix))]
+pub mod tests {
+    use super::create_launchers;
+    use std::fs::{self, Permissions};
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::Path;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_create_launchers() {
+        let dir = tempdir().expect("Could not create temporary directory");
+        for &(launcher, cli) in &[("npm", "npm-cli.js"), ("npx", "npx-cli.js")] {
+            fs::write(dir.path().join(launcher), "#!/bin/sh\n").unwrap();
+            let cli = dir.path().join(cli);
+            fs::write(&cli, "#!/usr/bin/env node\n").unwrap();
+            fs::set_permissions(&cli, Permissions::from_mode(0o644)).unwrap();
+        }
+
+        create_launchers(dir.path()).unwrap();
+
+        for &(launcher, cli) in &[("npm", "npm-cli.js"), ("npx", "npx-cli.js")] {
+            let launcher = dir.path().join(launcher);
+            assert_eq!(fs::read_link(&launcher).unwrap(), Path::new(cli));
+            let mode = fs::metadata(&launcher).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o755);
+        }
+    }
+}
@@ -1,12 +1,18 @@
 use std::fmt::{self, Display};
 
-use super::Tool;
+use super::{debug_already_fetched, Tool};
 use crate::error::ErrorDetails;
 use crate::session::Session;
 use crate::style::tool_version;
 use semver::Version;
 use volta_fail::Fallible;
 
+mod fetch;
+mod resolve;
+mod serial;
+
+pub use resolve::resolve_npm_overlay;
+
 /// The Tool implementation for fetching and installing Npm (Unimplemented)
 #[derive(Debug)]
 pub struct Npm {
@@ -17,6 +23,23 @@ impl Npm {
     pub fn new(version: Version) -> Self {
         Npm { version }
     }
+
+    pub(crate) fn fetch_internal(&self, session: &mut Session) -> Fallible<()> {
+        let inventory = session.inventory()?;
+        if inventory.npm.versions.contains(&self.version) {
+            debug_already_fetched(self);
+            return Ok(());
+        }
+
+        fetch::fetch(&self.version)?;
+        session
+            .inventory_mut()?
+            .npm
+            .versions
+            .insert(self.version.clone());
+
+        Ok(())
+    }
 }
 
 impl Tool for Npm {
@@ -0,0 +1,194 @@
+//! Provides resolution of npm requirements into specific versions, using the npm registry
+
+use std::collections::BTreeSet;
+
+use super::super::registry_fetch_error;
+use super::serial;
+use crate::error::ErrorDetails;
+use crate::session::Session;
+use crate::style::progress_spinner;
+use crate::timing::{self, Phase};
+use crate::tool::node::bundled_npm_version;
+use cfg_if::cfg_if;
+use log::debug;
+use reqwest::header::ACCEPT;
+use semver::{Version, VersionReq};
+use volta_fail::{Fallible, ResultExt};
+
+// ISSUE (#86): Move public repository URLs to config file
+cfg_if! {
+    if #[cfg(feature = "mock-network")] {
+        fn public_npm_version_index() -> String {
+            format!("{}/npm", mockito::SERVER_URL)
+        }
+    } else {
+        /// Returns the URL of the npm package metadata on the public npm registry.
+        fn public_npm_version_index() -> String {
+            "https://registry.npmjs.org/npm".to_string()
+        }
+    }
+}
+
+/// The media type of the abbreviated package metadata, which is much smaller than the full
+/// document and has everything needed for resolution.
+const ABBREVIATED_METADATA: &'static str = "application/vnd.npm.install-v1+json";
+
+/// Resolves the version of npm to overlay on the given Node version, for a project that
+/// specifies a range for npm.
+///
+/// Returns `None` if the npm bundled with Node already satisfies the requirement. Otherwise,
+/// an npm that has already been fetched is preferred over querying the registry, so that
+/// running tools in the project does not require network access every time.
+pub fn resolve_npm_overlay(
+    node: &Version,
+    requirement: &VersionReq,
+    session: &mut Session,
+) -> Fallible<Option<Version>> {
+    let bundled = bundled_npm_version(node, session)?;
+    let _span = timing::span(Phase::Resolution);
+
+    match choose_overlay(&bundled, requirement, &session.inventory()?.npm.versions) {
+        Overlay::Bundled => {
+            debug!(
+                "Bundled npm@{} matches requirement '{}', no overlay needed",
+                bundled, requirement
+            );
+            Ok(None)
+        }
+        Overlay::Fetched(version) => {
+            debug!(
+                "Found fetched npm@{} matching requirement '{}'",
+                version, requirement
+            );
+            Ok(Some(version))
+        }
+        Overlay::Registry => resolve_semver(requirement).map(Some),
+    }
+}
+
+/// Where the npm for a project that specifies a range comes from.
+#[derive(Debug, PartialEq)]
+enum Overlay {
+    /// The npm bundled with Node satisfies the range
+    Bundled,
+    /// An npm that has already been fetched satisfies the range
+    Fetched(Version),
+    /// A matching npm has to be found in the registry
+    Registry,
+}
+
+fn choose_overlay(
+    bundled: &Version,
+    requirement: &VersionReq,
+    fetched: &BTreeSet<Version>,
+) -> Overlay {
+    if requirement.matches(bundled) {
+        return Overlay::Bundled;
+    }
+
+    match fetched
+        .iter()
+        .rev()
+        .find(|version| requirement.matches(version))
+    {
+        Some(version) => Overlay::Fetched(version.clone()),
+        None => Overlay::Registry,
+    }
+}
+
+fn resolve_semver(matching: &VersionReq) -> Fallible<Version> {
+    let url = public_npm_version_index();
+
+    let _span = timing::span(Phase::Network);
+    let spinner = progress_spinner(&format!("Fetching public registry: {}", url));
+    let response_text = reqwest::Client::new()
+        .get(&url)
+        .header(ACCEPT, ABBREVIATED_METADATA)
+        .send()
+        .and_then(|resp| resp.error_for_status())
+        .and_then(|mut resp| resp.text())
+        .with_context(registry_fetch_error("npm", &url))?;
+    let raw: serial::RawNpmIndex = serde_json::de::from_str(&response_text).with_context(|_| {
+        ErrorDetails::ParseNpmIndexError {
+            from_url: url.clone(),
+        }
+    })?;
+    let index = NpmIndex::from(raw);
+    spinner.finish_and_clear();
+
+    match index
+        .entries
+        .into_iter()
+        .rev()
+        .find(|v| matching.matches(v))
+    {
+        Some(version) => {
+            debug!(
+                "Found npm@{} matching requirement '{}' from {}",
+                version, matching, url
+            );
+            Ok(version)
+        }
+        None => Err(ErrorDetails::NpmVersionNotFound {
+            matching: matching.to_string(),
+        }
+        .into()),
+    }
+}
+
+/// The set of npm versions published to the registry.
+pub struct NpmIndex {
+    pub(super) entries: BTreeSet<Version>,
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::{choose_overlay, Overlay};
+    use crate::version::VersionSpec;
+    use semver::Version;
+    use std::collections::BTreeSet;
+
+    fn versions(versions: &[&str]) -> BTreeSet<Version> {
+        versions
+            .iter()
+            .map(|version| Version::parse(version).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_choose_overlay_bundled() {
+        let bundled = Version::parse("6.4.1").unwrap();
+        let requirement = VersionSpec::parse_requirements("^6.4").unwrap();
+
+        assert_eq!(
+            choose_overlay(&bundled, &requirement, &versions(&["6.9.0"])),
+            Overlay::Bundled
+        );
+    }
+
+    #[test]
+    fn test_choose_overlay_fetched() {
+        let bundled = Version::parse("6.4.1").unwrap();
+        let requirement = VersionSpec::parse_requirements("^6.9").unwrap();
+
+        assert_eq!(
+            choose_overlay(
+                &bundled,
+                &requirement,
+                &versions(&["6.9.0", "6.10.2", "7.0.0"])
+            ),
+            Overlay::Fetched(Version::parse("6.10.2").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_choose_overlay_registry() {
+        let bundled = Version::parse("6.4.1").unwrap();
+        let requirement = VersionSpec::parse_requirements("^6.9").unwrap();
+
+        assert_eq!(
+            choose_overlay(&bundled, &requirement, &versions(&["6.4.1", "7.0.0"])),
+            Overlay::Registry
+        );
+    }
+}
@@ -0,0 +1,24 @@
+use std::collections::{BTreeSet, HashMap};
+
+use super::resolve::NpmIndex;
+use semver::Version;
+use serde::de::IgnoredAny;
+use serde::Deserialize;
+
+/// The abbreviated package metadata for npm from the npm registry. Only the set of published
+/// versions is needed, so the per-version metadata is skipped.
+#[derive(Deserialize)]
+pub struct RawNpmIndex {
+    pub versions: HashMap<String, IgnoredAny>,
+}
+
+impl From<RawNpmIndex> for NpmIndex {
+    fn from(raw: RawNpmIndex) -> NpmIndex {
+        let entries: BTreeSet<Version> = raw
+            .versions
+            .keys()
+            .filter_map(|version| Version::parse(version).ok())
+            .collect();
+        NpmIndex { entries }
+    }
+}
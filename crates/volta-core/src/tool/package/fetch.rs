@@ -8,12 +8,15 @@ use super::super::download_tool_error;
 use crate::error::ErrorDetails;
 use crate::fs::{create_staging_dir, ensure_dir_does_not_exist, read_dir_eager, read_file};
 use crate::path;
+use crate::permissions;
 use crate::style::{progress_bar, tool_version};
+use crate::timing::{self, Phase};
 use crate::tool::{self, PackageDetails};
 use crate::version::VersionSpec;
 use archive::{Archive, Tarball};
 use fs_utils::ensure_containing_dir_exists;
 use log::debug;
+use reqwest::header::HeaderMap;
 use semver::Version;
 use sha1::{Digest, Sha1};
 use volta_fail::{Fallible, ResultExt};
@@ -22,6 +25,9 @@ pub fn fetch(name: &str, details: &PackageDetails) -> Fallible<()> {
     let version_string = details.version.to_string();
     let cache_file = path::package_distro_file(&name, &version_string)?;
     let shasum_file = path::package_distro_shasum(&name, &version_string)?;
+    permissions::check_path(&cache_file)?;
+    permissions::check_path(&shasum_file)?;
+    permissions::check_path(&path::package_image_dir(&name, &version_string)?)?;
 
     let (archive, cached) = match load_cached_distro(&cache_file, &shasum_file) {
         Some(archive) => {
@@ -36,6 +42,7 @@ pub fn fetch(name: &str, details: &PackageDetails) -> Fallible<()> {
             let archive = fetch_remote_distro(
                 tool::Spec::Package(name.into(), VersionSpec::exact(&details.version)),
                 &details.tarball_url,
+                &details.headers,
                 &cache_file,
             )?;
             (archive, false)
@@ -79,12 +86,20 @@ fn load_cached_distro(file: &Path, shasum_file: &Path) -> Option<Box<dyn Archive
     Tarball::load(distro).ok()
 }
 
-fn fetch_remote_distro(spec: tool::Spec, url: &str, path: &Path) -> Fallible<Box<Archive>> {
+fn fetch_remote_distro(
+    spec: tool::Spec,
+    url: &str,
+    headers: &HeaderMap,
+    path: &Path,
+) -> Fallible<Box<Archive>> {
+    let _span = timing::span(Phase::Network);
     debug!("Downloading {} from {}, to {}", &spec, &url, path.display());
-    Tarball::fetch(url, path).with_context(download_tool_error(spec, url.to_string()))
+    Tarball::fetch_with_headers(url, headers, path)
+        .with_context(download_tool_error(spec, url.to_string()))
 }
 
 fn unpack_archive(archive: Box<Archive>, name: &str, version: &Version) -> Fallible<()> {
+    let _span = timing::span(Phase::Unpack);
     let temp = create_staging_dir()?;
     debug!("Unpacking {} into '{}'", name, temp.path().display());
 
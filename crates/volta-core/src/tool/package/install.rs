@@ -10,7 +10,7 @@ use super::super::Spec;
 use super::bin_full_path;
 use crate::command::create_command;
 use crate::error::ErrorDetails;
-use crate::manifest::BinManifest;
+use crate::manifest::{BinManifest, Manifest};
 use crate::path;
 use crate::platform::{Image, PlatformSpec};
 use crate::session::Session;
@@ -18,7 +18,7 @@ use crate::shim;
 use crate::style::{progress_spinner, tool_version};
 use crate::version::VersionSpec;
 use atty::Stream;
-use log::debug;
+use log::{debug, warn};
 use semver::Version;
 use volta_fail::{throw, Fallible, ResultExt};
 
@@ -81,6 +81,9 @@ pub struct PackageConfig {
 ///     }
 ///   }
 /// }
+///
+/// Aliases created with `volta alias` are stored in the same place, with `"alias": true` and
+/// the aliased tool (e.g. `node`) as both the `package` and the `path`.
 pub struct BinConfig {
     /// The binary name
     pub name: String,
@@ -94,6 +97,9 @@ pub struct BinConfig {
     pub platform: PlatformSpec,
     /// The loader information for the script, if any
     pub loader: Option<BinLoader>,
+    /// Whether this is an alias created by `volta alias`, which runs the `path` executable
+    /// from the platform instead of a binary from an installed package
+    pub alias: bool,
 }
 
 /// Information about the Shebang script loader (e.g. `#!/usr/bin/env node`)
@@ -110,10 +116,11 @@ pub struct BinLoader {
 pub fn install(
     name: &str,
     version: &Version,
+    with_dependencies_bins: bool,
     session: &mut Session,
 ) -> Fallible<HashMap<String, String>> {
     let package_dir = path::package_image_dir(name, &version.to_string())?;
-    let bin_map = read_bins(name, version)?;
+    let mut bin_map = read_bins(name, version)?;
     let display = tool_version(name, version);
 
     let engine = determine_engine(&package_dir, &display)?;
@@ -125,6 +132,10 @@ pub fn install(
     let image = platform.clone().checkout(session)?;
 
     install_dependencies(&package_dir, image, &display)?;
+    if with_dependencies_bins {
+        let dependency_bins = read_dependency_bins(name, &package_dir, &bin_map, shim_conflict)?;
+        bin_map.extend(dependency_bins);
+    }
     write_configs(name, version, &platform, &bin_map)?;
 
     Ok(bin_map)
@@ -172,6 +183,7 @@ fn write_configs(
             path: bin_path.clone(),
             platform: platform.clone(),
             loader,
+            alias: false,
         })
         .write()?;
 
@@ -252,7 +264,7 @@ fn read_bins(name: &str, version: &Version) -> Fallible<HashMap<String, String>>
             let bin_config = BinConfig::from_file(bin_config_file)?;
             // if the bin was installed by the package that is currently being installed,
             // that's ok - otherwise it's an error
-            if name != bin_config.package {
+            if bin_config.alias || name != bin_config.package {
                 throw!(ErrorDetails::BinaryAlreadyInstalled {
                     bin_name: bin_name.clone(),
                     existing_package: bin_config.package,
@@ -265,6 +277,97 @@ fn read_bins(name: &str, version: &Version) -> Fallible<HashMap<String, String>>
     Ok(bin_map)
 }
 
+/// Read the bins of the direct dependencies of an installed package, with paths relative to the
+/// package. Bins that would collide with the package's own bins, another dependency's bins, or
+/// an existing shim (as described by `conflict`) are skipped with a warning, since they weren't
+/// explicitly requested.
+fn read_dependency_bins<F>(
+    name: &str,
+    package_dir: &Path,
+    package_bins: &HashMap<String, String>,
+    conflict: F,
+) -> Fallible<HashMap<String, String>>
+where
+    F: Fn(&str, &str) -> Fallible<Option<String>>,
+{
+    let mut dependencies: Vec<String> = Manifest::for_dir(package_dir)?
+        .dependencies
+        .into_iter()
+        .map(|(dependency, _version)| dependency)
+        .collect();
+    dependencies.sort();
+
+    let mut providers: HashMap<String, String> = package_bins
+        .keys()
+        .map(|bin_name| (bin_name.clone(), name.to_string()))
+        .collect();
+    let mut bin_map = HashMap::new();
+
+    for dependency in dependencies {
+        let dependency_dir = Path::new("node_modules").join(&dependency);
+        let manifest = match BinManifest::for_dir(&package_dir.join(&dependency_dir)) {
+            Ok(manifest) => manifest,
+            Err(_) => {
+                debug!("Dependency '{}' of {} is not installed", dependency, name);
+                continue;
+            }
+        };
+
+        let mut bins: Vec<(String, String)> = manifest.bin.into_iter().collect();
+        bins.sort();
+        for (bin_name, bin_path) in bins {
+            if let Some(provider) = providers.get(&bin_name) {
+                warn!(
+                    "Skipping executable '{}' from dependency '{}', as it is already provided by '{}'",
+                    bin_name, dependency, provider
+                );
+                continue;
+            }
+            if let Some(existing) = conflict(name, &bin_name)? {
+                warn!(
+                    "Skipping executable '{}' from dependency '{}', as it would conflict with {}",
+                    bin_name, dependency, existing
+                );
+                continue;
+            }
+
+            let relative_path = dependency_dir.join(&bin_path);
+            bin_map.insert(
+                bin_name.clone(),
+                relative_path.to_string_lossy().into_owned(),
+            );
+            providers.insert(bin_name, dependency.clone());
+        }
+    }
+
+    Ok(bin_map)
+}
+
+/// Describes the existing shim that a bin of the given package would replace, if any
+fn shim_conflict(name: &str, bin_name: &str) -> Fallible<Option<String>> {
+    if !shim::is_3p_shim(bin_name) {
+        return Ok(Some(format!("the built-in '{}' shim", bin_name)));
+    }
+
+    let bin_config_file = path::user_tool_bin_config(bin_name)?;
+    if bin_config_file.exists() {
+        let bin_config = BinConfig::from_file(bin_config_file)?;
+        if bin_config.alias {
+            return Ok(Some(format!("the alias '{}'", bin_name)));
+        }
+        if bin_config.package != name {
+            return Ok(Some(format!(
+                "the executable installed by '{}'",
+                bin_config.package
+            )));
+        }
+    } else if path::shim_file(bin_name)?.exists() {
+        return Ok(Some(format!("the existing shim '{}'", bin_name)));
+    }
+
+    Ok(None)
+}
+
 mod os {
     use std::io;
     use std::path::Path;
@@ -338,3 +441,66 @@ mod os {
         }
     }
 }
+
+#[cfg(test)]
+pub mod tests {
+    use super::{read_dependency_bins, shim_conflict};
+    use std::collections::HashMap;
+    use std::path::{Path, PathBuf};
+
+    fn fixture_path(fixture_dir: &str) -> PathBuf {
+        let mut cargo_manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        cargo_manifest_dir.push("fixtures");
+        cargo_manifest_dir.push(fixture_dir);
+        cargo_manifest_dir
+    }
+
+    fn dependency_bin_path(dependency: &str, bin_path: &str) -> String {
+        Path::new("node_modules")
+            .join(dependency)
+            .join(bin_path)
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn test_shim_conflict_builtin() {
+        assert_eq!(
+            shim_conflict("npm-wrapper", "npm").unwrap(),
+            Some("the built-in 'npm' shim".to_string())
+        );
+    }
+
+    #[test]
+    fn test_read_dependency_bins_skips_conflicts() {
+        let mut package_bins = HashMap::new();
+        package_bins.insert("cowsay-tools".to_string(), "./cli.js".to_string());
+
+        // Looking up the existing shims is replaced, so that the test doesn't depend on the
+        // Volta directory of the machine running it
+        let bins = read_dependency_bins(
+            "cowsay-tools",
+            &fixture_path("dependency_bins"),
+            &package_bins,
+            |_, bin_name| match bin_name {
+                "npm" => Ok(Some("the built-in 'npm' shim".to_string())),
+                _ => Ok(None),
+            },
+        )
+        .unwrap();
+
+        // `npm` clashes with an existing shim, `cowsay-tools` with the package's own bin, and
+        // `missing-dependency` is not installed, so only the other bins are exposed
+        let mut expected = HashMap::new();
+        expected.insert(
+            "cowsay-plugin".to_string(),
+            dependency_bin_path("cowsay-plugin", "./plugin.js"),
+        );
+        expected.insert(
+            "npm-wrapper".to_string(),
+            dependency_bin_path("npm-wrapper", "./bin/wrapper.js"),
+        );
+
+        assert_eq!(bins, expected);
+    }
+}
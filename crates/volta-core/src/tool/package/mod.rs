@@ -6,10 +6,12 @@ use super::{debug_already_fetched, info_fetched, Tool};
 use crate::error::ErrorDetails;
 use crate::fs::{delete_dir_error, delete_file_error, dir_entry_match};
 use crate::path;
+use crate::permissions;
 use crate::session::Session;
 use crate::shim;
 use crate::style::{success_prefix, tool_version};
 use log::info;
+use reqwest::header::HeaderMap;
 use semver::Version;
 use volta_fail::{Fallible, ResultExt};
 
@@ -45,6 +47,8 @@ pub struct PackageDetails {
     pub(crate) version: Version,
     pub(crate) tarball_url: String,
     pub(crate) shasum: String,
+    /// HTTP headers to send when downloading the tarball, provided by an `exec` hook
+    pub(crate) headers: HeaderMap,
 }
 
 /// The Tool implementation for fetching and installing 3rd-party packages
@@ -52,11 +56,25 @@ pub struct PackageDetails {
 pub struct Package {
     pub(crate) name: String,
     pub(crate) details: PackageDetails,
+    /// Whether installing also exposes the executables of the package's direct dependencies
+    pub(crate) with_dependencies_bins: bool,
 }
 
 impl Package {
     pub fn new(name: String, details: PackageDetails) -> Self {
-        Package { name, details }
+        Package {
+            name,
+            details,
+            with_dependencies_bins: false,
+        }
+    }
+
+    /// Also exposes the executables of the package's direct dependencies when it is installed
+    pub fn with_dependencies_bins(self) -> Self {
+        Package {
+            with_dependencies_bins: true,
+            ..self
+        }
     }
 
     fn fetch_internal(&self) -> Fallible<()> {
@@ -97,7 +115,12 @@ impl Tool for Package {
         } else {
             self.fetch_internal()?;
 
-            let bin_map = install::install(&self.name, &self.details.version, session)?;
+            let bin_map = install::install(
+                &self.name,
+                &self.details.version,
+                self.with_dependencies_bins,
+                session,
+            )?;
 
             let bins = bin_map
                 .keys()
@@ -133,6 +156,10 @@ impl Display for Package {
 pub fn uninstall(name: &str) -> Fallible<()> {
     // if the package config file exists, use that to remove any installed bins and shims
     let package_config_file = path::user_package_config_file(name)?;
+    let package_image_dir = path::package_image_root_dir()?.join(name);
+    permissions::check_path(&package_config_file)?;
+    permissions::check_path(&package_image_dir)?;
+
     if package_config_file.exists() {
         let package_config = PackageConfig::from_file(&package_config_file)?;
 
@@ -150,7 +177,6 @@ pub fn uninstall(name: &str) -> Fallible<()> {
     }
 
     // if any unpacked and initialized packages exists, remove them
-    let package_image_dir = path::package_image_root_dir()?.join(name);
     if package_image_dir.exists() {
         fs::remove_dir_all(&package_image_dir)
             .with_context(delete_dir_error(&package_image_dir))?;
@@ -178,7 +204,7 @@ fn binaries_from_package(package: &str) -> Fallible<Vec<String>> {
         dir_entry_match(&bin_config_dir, |entry| {
             let path = entry.path();
             if let Ok(config) = BinConfig::from_file(path) {
-                if config.package == package.to_string() {
+                if !config.alias && config.package == package.to_string() {
                     return Some(config.name);
                 }
             };
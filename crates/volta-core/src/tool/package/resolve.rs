@@ -4,12 +4,16 @@ use std::process::Command;
 
 use crate::command::create_command;
 use crate::error::ErrorDetails;
+use crate::hook::tool::Location;
 use crate::hook::ToolHooks;
 use crate::session::Session;
 use crate::style::{progress_spinner, tool_version};
+use crate::timing::{self, Phase};
 use crate::tool::{Package, PackageDetails};
 use crate::version::VersionSpec;
 use log::debug;
+use reqwest::header::HeaderMap;
+use reqwest::Url;
 use semver::{Version, VersionReq};
 use volta_fail::{throw, Fallible, ResultExt};
 
@@ -33,8 +37,8 @@ fn resolve_latest(name: &str, hooks: Option<&ToolHooks<Package>>) -> Fallible<Pa
             ..
         }) => {
             debug!("Using packages.latest hook to determine package metadata URL");
-            let url = hook.resolve(&name)?;
-            resolve_package_metadata(name, &url)?.into()
+            let location = hook.resolve(name, name)?;
+            resolve_package_metadata(name, &location)?
         }
         _ => npm_view_query(name, "latest")?,
     };
@@ -73,8 +77,8 @@ fn resolve_semver(
             ..
         }) => {
             debug!("Using packages.index hook to determine package metadata URL");
-            let url = hook.resolve(&name)?;
-            resolve_package_metadata(name, &url)?.into()
+            let location = hook.resolve(name, name)?;
+            resolve_package_metadata(name, &location)?
         }
         _ => npm_view_query(name, &matching.to_string())?,
     };
@@ -114,6 +118,7 @@ fn npm_view_query(name: &str, version: &str) -> Fallible<PackageIndex> {
     let mut command = npm_view_command_for(name, version);
     debug!("Running command: `{:?}`", command);
 
+    let _span = timing::span(Phase::Network);
     let spinner = progress_spinner(&format!(
         "Querying metadata for {}",
         tool_version(name, version)
@@ -177,12 +182,12 @@ fn npm_view_command_for(name: &str, version: &str) -> Command {
 }
 
 // fetch metadata for the input url
-fn resolve_package_metadata(
-    package_name: &str,
-    package_info_url: &str,
-) -> Fallible<super::serial::RawPackageMetadata> {
+fn resolve_package_metadata(package_name: &str, location: &Location) -> Fallible<PackageIndex> {
+    let package_info_url = location.url.as_str();
+    let _span = timing::span(Phase::Network);
     let spinner = progress_spinner(&format!("Fetching package metadata: {}", package_info_url));
-    let response_text = reqwest::get(package_info_url)
+    let response_text = location
+        .get()
         .and_then(|resp| resp.error_for_status())
         .and_then(|mut resp| resp.text())
         .with_context(|err| match err.status() {
@@ -200,5 +205,74 @@ fn resolve_package_metadata(
         })?;
 
     spinner.finish_and_clear();
-    Ok(metadata)
+
+    let mut index = PackageIndex::from(metadata);
+    for details in index.entries.iter_mut() {
+        details.headers = tarball_headers(&details.tarball_url, location);
+    }
+    Ok(index)
+}
+
+// the headers to send when downloading a tarball: the hook's headers usually hold credentials
+// for its registry, so they are only sent to tarballs served from the same origin
+fn tarball_headers(tarball_url: &str, location: &Location) -> HeaderMap {
+    if same_origin(tarball_url, &location.url) {
+        location.headers.clone()
+    } else {
+        HeaderMap::new()
+    }
+}
+
+// whether two URLs have the same scheme, host, and port
+fn same_origin(first: &str, second: &str) -> bool {
+    match (Url::parse(first), Url::parse(second)) {
+        (Ok(first), Ok(second)) => {
+            first.scheme() == second.scheme()
+                && first.host_str() == second.host_str()
+                && first.port_or_known_default() == second.port_or_known_default()
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::tarball_headers;
+    use crate::hook::tool::Location;
+    use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+
+    fn registry_location() -> Location {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer abc123"));
+        Location {
+            url: "https://registry.example.com/cowsay".to_string(),
+            headers,
+            checksum: None,
+        }
+    }
+
+    #[test]
+    fn test_tarball_headers_same_origin() {
+        let location = registry_location();
+        let headers = tarball_headers(
+            "https://registry.example.com:443/cowsay/-/cowsay-1.4.0.tgz",
+            &location,
+        );
+
+        assert_eq!(headers, location.headers);
+    }
+
+    #[test]
+    fn test_tarball_headers_other_origin() {
+        let location = registry_location();
+
+        for tarball_url in &[
+            "https://cdn.example.com/cowsay/-/cowsay-1.4.0.tgz",
+            "http://registry.example.com/cowsay/-/cowsay-1.4.0.tgz",
+            "https://registry.example.com:8443/cowsay/-/cowsay-1.4.0.tgz",
+            "not a url",
+        ] {
+            assert!(tarball_headers(tarball_url, &location).is_empty());
+        }
+    }
 }
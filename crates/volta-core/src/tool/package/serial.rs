@@ -11,6 +11,7 @@ use crate::path;
 use crate::toolchain;
 use crate::version::version_serde;
 use fs_utils::ensure_containing_dir_exists;
+use reqwest::header::HeaderMap;
 use semver::Version;
 use serde::{Deserialize, Serialize};
 use volta_fail::{Fallible, ResultExt, VoltaError};
@@ -58,6 +59,7 @@ impl From<RawPackageMetadata> for PackageIndex {
                 version: version_info.version,
                 tarball_url: version_info.dist.tarball,
                 shasum: version_info.dist.shasum,
+                headers: HeaderMap::new(),
             })
             .collect();
 
@@ -106,6 +108,7 @@ impl From<NpmViewData> for PackageDetails {
             version: view_data.version,
             tarball_url: view_data.dist.tarball,
             shasum: view_data.dist.shasum,
+            headers: HeaderMap::new(),
         }
     }
 }
@@ -194,6 +197,8 @@ pub struct RawBinConfig {
     pub platform: toolchain::serial::Platform,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub loader: Option<RawBinLoader>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub alias: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -232,6 +237,11 @@ impl BinConfig {
             read_to_string(&file).with_context(|_| ErrorDetails::ReadBinConfigError { file })?;
         RawBinConfig::from_json(config_src)?.try_into()
     }
+
+    /// Write the config to disk
+    pub fn write(self) -> Fallible<()> {
+        RawBinConfig::from(self).write()
+    }
 }
 
 impl TryFrom<RawBinConfig> for BinConfig {
@@ -251,6 +261,7 @@ impl TryFrom<RawBinConfig> for BinConfig {
             path: raw.path,
             platform,
             loader: raw.loader.map(|l| l.into()),
+            alias: raw.alias,
         })
     }
 }
@@ -264,6 +275,7 @@ impl From<BinConfig> for RawBinConfig {
             path: full.path,
             platform: full.platform.to_serial(),
             loader: full.loader.map(Into::into),
+            alias: full.alias,
         }
     }
 }
@@ -3,12 +3,15 @@
 use std::fs::{rename, File};
 use std::path::{Path, PathBuf};
 
-use super::super::download_tool_error;
+use super::super::{download_tool_error, fetch_verified_distro};
 use crate::error::ErrorDetails;
 use crate::fs::{create_staging_dir, create_staging_file};
+use crate::hook::tool::Location;
 use crate::hook::ToolHooks;
 use crate::path;
+use crate::permissions;
 use crate::style::{progress_bar, tool_version};
+use crate::timing::{self, Phase};
 use crate::tool::{self, Yarn};
 use crate::version::VersionSpec;
 use archive::{Archive, Tarball};
@@ -33,6 +36,8 @@ cfg_if! {
 pub fn fetch(version: &Version, hooks: Option<&ToolHooks<Yarn>>) -> Fallible<()> {
     let yarn_dir = path::yarn_inventory_dir()?;
     let cache_file = yarn_dir.join(path::yarn_distro_file_name(&version.to_string()));
+    permissions::check_path(&cache_file)?;
+    permissions::check_path(&path::yarn_image_dir(&version.to_string())?)?;
 
     let (archive, staging) = match load_cached_distro(&cache_file) {
         Some(archive) => {
@@ -45,8 +50,8 @@ pub fn fetch(version: &Version, hooks: Option<&ToolHooks<Yarn>>) -> Fallible<()>
         }
         None => {
             let staging = create_staging_file()?;
-            let remote_url = determine_remote_url(&version, hooks)?;
-            let archive = fetch_remote_distro(&version, &remote_url, staging.path())?;
+            let location = determine_remote_url(&version, hooks)?;
+            let archive = fetch_remote_distro(&version, &location, staging.path())?;
             (archive, Some(staging))
         }
     };
@@ -71,6 +76,7 @@ pub fn fetch(version: &Version, hooks: Option<&ToolHooks<Yarn>>) -> Fallible<()>
 
 /// Unpack the yarn archive into the image directory so that it is ready for use
 fn unpack_archive(archive: Box<Archive>, version: &Version) -> Fallible<()> {
+    let _span = timing::span(Phase::Unpack);
     let temp = create_staging_dir()?;
     debug!("Unpacking yarn into '{}'", temp.path().display());
 
@@ -128,7 +134,7 @@ fn load_cached_distro(file: &PathBuf) -> Option<Box<Archive>> {
 }
 
 /// Determine the remote URL to download from, using the hooks if available
-fn determine_remote_url(version: &Version, hooks: Option<&ToolHooks<Yarn>>) -> Fallible<String> {
+fn determine_remote_url(version: &Version, hooks: Option<&ToolHooks<Yarn>>) -> Fallible<Location> {
     let version_str = version.to_string();
     let distro_file_name = path::yarn_distro_file_name(&version_str);
     match hooks {
@@ -137,26 +143,35 @@ fn determine_remote_url(version: &Version, hooks: Option<&ToolHooks<Yarn>>) -> F
             ..
         }) => {
             debug!("Using yarn.distro hook to determine download URL");
-            hook.resolve(&version, &distro_file_name)
+            hook.resolve("yarn", &version, &distro_file_name)
         }
         _ => Ok(format!(
             "{}/v{}/{}",
             public_yarn_server_root(),
             version_str,
             distro_file_name
-        )),
+        )
+        .into()),
     }
 }
 
 /// Fetch the distro archive from the internet
 fn fetch_remote_distro(
     version: &Version,
-    url: &str,
+    location: &Location,
     staging_path: &Path,
 ) -> Fallible<Box<Archive>> {
+    let _span = timing::span(Phase::Network);
+    let url = &location.url;
+    let spec = tool::Spec::Yarn(VersionSpec::exact(&version));
     debug!("Downloading {} from {}", tool_version("yarn", version), url);
-    Tarball::fetch(url, staging_path).with_context(download_tool_error(
-        tool::Spec::Yarn(VersionSpec::exact(&version)),
-        url,
-    ))
+
+    match location.checksum {
+        Some(ref checksum) => {
+            let distro = fetch_verified_distro(spec.clone(), location, checksum, staging_path)?;
+            Tarball::load(distro).with_context(download_tool_error(spec, url))
+        }
+        None => Tarball::fetch_with_headers(url, &location.headers, staging_path)
+            .with_context(download_tool_error(spec, url)),
+    }
 }
@@ -5,9 +5,11 @@ use std::collections::BTreeSet;
 use super::super::registry_fetch_error;
 use super::serial;
 use crate::error::ErrorDetails;
+use crate::hook::tool::Location;
 use crate::hook::ToolHooks;
 use crate::session::Session;
 use crate::style::progress_spinner;
+use crate::timing::{self, Phase};
 use crate::tool::Yarn;
 use crate::version::VersionSpec;
 use cfg_if::cfg_if;
@@ -46,17 +48,21 @@ pub fn resolve(matching: VersionSpec, session: &mut Session) -> Fallible<Version
 }
 
 fn resolve_latest(hooks: Option<&ToolHooks<Yarn>>) -> Fallible<Version> {
-    let url = match hooks {
+    let location: Location = match hooks {
         Some(&ToolHooks {
             latest: Some(ref hook),
             ..
         }) => {
             debug!("Using yarn.latest hook to determine latest-version URL");
-            hook.resolve("latest-version")?
+            hook.resolve("yarn", "latest-version")?
         }
-        _ => public_yarn_latest_version(),
+        _ => public_yarn_latest_version().into(),
     };
-    let response_text = reqwest::get(&url)
+
+    let url = &location.url;
+    let _span = timing::span(Phase::Network);
+    let response_text = location
+        .get()
         .and_then(|mut resp| resp.text())
         .with_context(|_| ErrorDetails::YarnLatestFetchError {
             from_url: url.clone(),
@@ -67,21 +73,24 @@ fn resolve_latest(hooks: Option<&ToolHooks<Yarn>>) -> Fallible<Version> {
 }
 
 fn resolve_semver(matching: VersionReq, hooks: Option<&ToolHooks<Yarn>>) -> Fallible<Version> {
-    let url = match hooks {
+    let location: Location = match hooks {
         Some(&ToolHooks {
             index: Some(ref hook),
             ..
         }) => {
             debug!("Using yarn.index hook to determine yarn index URL");
-            hook.resolve("releases")?
+            hook.resolve("yarn", "releases")?
         }
-        _ => public_yarn_version_index(),
+        _ => public_yarn_version_index().into(),
     };
 
+    let url = &location.url;
+    let _span = timing::span(Phase::Network);
     let spinner = progress_spinner(&format!("Fetching public registry: {}", url));
-    let releases: serial::RawYarnIndex = reqwest::get(&url)
+    let releases: serial::RawYarnIndex = location
+        .get()
         .and_then(|mut resp| resp.json())
-        .with_context(registry_fetch_error("Yarn", &url))?;
+        .with_context(registry_fetch_error("Yarn", url))?;
     let index = YarnIndex::from(releases);
     let releases = index.entries;
     spinner.finish_and_clear();
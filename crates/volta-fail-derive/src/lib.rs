@@ -31,7 +31,9 @@ pub fn volta_fail(token_stream: TokenStream) -> TokenStream {
 
                 Meta(NameValue(ref m)) if m.ident == "code" => {
                     if let Lit::Str(s) = &m.lit {
-                        code = Ident::new(&s.value(), Span::call_site());
+                        let value = s.value();
+                        validate_code(&value);
+                        code = Ident::new(&value, Span::call_site());
                         code_set = true;
                     } else {
                         // Defined, but not a string.
@@ -65,6 +67,14 @@ pub fn volta_fail(token_stream: TokenStream) -> TokenStream {
     tokens.into()
 }
 
+/// Rejects `Success` as the exit code of an error. Any other name that is not a variant of
+/// `ExitCode` fails to compile in the generated impl, so the valid codes always match the enum.
+fn validate_code(name: &str) {
+    if name == "Success" {
+        panic!("#[volta_fail()]: 'Success' is not an error exit code");
+    }
+}
+
 fn get_volta_fail_meta_items(attr: &syn::Attribute) -> Option<Vec<NestedMeta>> {
     if attr.path.segments.len() == 1 && attr.path.segments[0].ident == "volta_fail" {
         match attr.interpret_meta() {
@@ -78,3 +88,20 @@ fn get_volta_fail_meta_items(attr: &syn::Attribute) -> Option<Vec<NestedMeta>> {
         None
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::validate_code;
+
+    #[test]
+    fn error_codes_are_valid() {
+        validate_code("UnknownError");
+        validate_code("ExecutableNotFound");
+    }
+
+    #[test]
+    #[should_panic(expected = "'Success' is not an error exit code")]
+    fn success_is_not_an_error_code() {
+        validate_code("Success");
+    }
+}
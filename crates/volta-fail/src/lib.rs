@@ -11,6 +11,26 @@
 //! method. The `VoltaFail::exit_code()` method allows each error type to indicate what
 //! the process exit code should be if the error is the reason for exiting Volta.
 //!
+//! # Exit codes
+//!
+//! Exit codes are part of Volta's public interface: scripts and CI systems use them to
+//! distinguish between categories of failure, so the numeric value of an `ExitCode`
+//! variant must never change once released. The space of codes is partitioned into
+//! ranges, and new categories must be allocated from the appropriate range:
+//!
+//! | Codes     | Meaning                                                            |
+//! |-----------|--------------------------------------------------------------------|
+//! | 0         | Success                                                            |
+//! | 1         | Unknown error                                                      |
+//! | 2         | Reserved (usage errors reported by shells and argument parsers)   |
+//! | 3 - 63    | Volta error categories (`InvalidArguments` through `NotYetImplemented`) |
+//! | 64 - 125  | Reserved for future use                                            |
+//! | 126 - 127 | The requested executable could not be run or could not be found, following the POSIX shell conventions |
+//! | 128+      | Never used by Volta; a tool killed by signal `N` exits with `128 + N` |
+//!
+//! Errors that originate in a tool run by Volta (for instance, `node` exiting with a
+//! non-zero status) are passed through unchanged rather than mapped into these ranges.
+//!
 //! # The `VoltaError` type and `Fallible` functions
 //!
 //! The main error type provided by this crate is `VoltaError`. This acts more
@@ -74,6 +94,19 @@
 //! struct UnexpectedEndOfString;
 //! ```
 //!
+//! The `code` must name one of the error variants of `ExitCode`. Since `Success` is not
+//! an error, it is rejected at compile time, as is any name that is not a variant.
+//!
+//! ```compile_fail
+//! # use failure::Fail;
+//! # use volta_fail::{ExitCode, VoltaFail};
+//! # use volta_fail_derive::*;
+//! #[derive(Debug, Fail, VoltaFail)]
+//! #[fail(display = "this is not a failure")]
+//! #[volta_fail(code = "Success")]
+//! struct NotAnError;
+//! ```
+//!
 //! # Throwing errors
 //!
 //! The `throw!` macro is a convenient syntax for an early exit with an error. It
@@ -195,6 +228,9 @@ macro_rules! throw {
 }
 
 /// Exit codes supported by the VoltaFail trait.
+///
+/// The numeric values are stable; see the [crate-level documentation](index.html#exit-codes)
+/// for the ranges each category must be allocated from.
 #[derive(Copy, Clone, Debug, Serialize)]
 pub enum ExitCode {
     /// No error occurred.
@@ -237,6 +273,68 @@ impl ExitCode {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::ExitCode;
+
+    #[test]
+    fn exit_codes_are_stable() {
+        assert_eq!(ExitCode::Success as i32, 0);
+        assert_eq!(ExitCode::UnknownError as i32, 1);
+        assert_eq!(ExitCode::InvalidArguments as i32, 3);
+        assert_eq!(ExitCode::NoVersionMatch as i32, 4);
+        assert_eq!(ExitCode::NetworkError as i32, 5);
+        assert_eq!(ExitCode::EnvironmentError as i32, 6);
+        assert_eq!(ExitCode::FileSystemError as i32, 7);
+        assert_eq!(ExitCode::ConfigurationError as i32, 8);
+        assert_eq!(ExitCode::NotYetImplemented as i32, 9);
+        assert_eq!(ExitCode::ExecutionFailure as i32, 126);
+        assert_eq!(ExitCode::ExecutableNotFound as i32, 127);
+    }
+
+    #[test]
+    fn exit_codes_are_in_documented_ranges() {
+        let codes = [
+            ExitCode::Success,
+            ExitCode::UnknownError,
+            ExitCode::InvalidArguments,
+            ExitCode::NoVersionMatch,
+            ExitCode::NetworkError,
+            ExitCode::EnvironmentError,
+            ExitCode::FileSystemError,
+            ExitCode::ConfigurationError,
+            ExitCode::NotYetImplemented,
+            ExitCode::ExecutionFailure,
+            ExitCode::ExecutableNotFound,
+        ];
+
+        for code in codes.iter() {
+            // Exhaustive, so that a new variant must be assigned one of the ranges here
+            let (low, high) = match code {
+                ExitCode::Success => (0, 0),
+                ExitCode::UnknownError => (1, 1),
+                ExitCode::InvalidArguments
+                | ExitCode::NoVersionMatch
+                | ExitCode::NetworkError
+                | ExitCode::EnvironmentError
+                | ExitCode::FileSystemError
+                | ExitCode::ConfigurationError
+                | ExitCode::NotYetImplemented => (3, 63),
+                ExitCode::ExecutionFailure | ExitCode::ExecutableNotFound => (126, 127),
+            };
+            let value = *code as i32;
+            assert!(
+                value >= low && value <= high,
+                "{:?} ({}) is outside of its range {} - {}",
+                code,
+                value,
+                low,
+                high
+            );
+        }
+    }
+}
+
 /// The failure trait for all Volta errors.
 pub trait VoltaFail: Fail {
     /// Returns the process exit code that should be returned if the process exits with this error.
@@ -1,7 +1,10 @@
+use std::path::PathBuf;
+
 use structopt::StructOpt;
 
 use crate::command::{self, Command};
 use volta_core::path;
+use volta_core::permissions;
 use volta_core::session::Session;
 use volta_fail::{ExitCode, Fallible};
 
@@ -37,6 +40,22 @@ pub(crate) struct Volta {
     )]
     pub(crate) quiet: bool,
 
+    #[structopt(
+        long = "timing",
+        help = "Prints how long each phase of the command took",
+        global = true
+    )]
+    pub(crate) timing: bool,
+
+    #[structopt(
+        long = "project",
+        value_name = "dir",
+        help = "Detects the project from <dir> instead of the current directory",
+        global = true,
+        parse(from_os_str)
+    )]
+    pub(crate) project: Option<PathBuf>,
+
     #[structopt(
         short = "v",
         long = "version",
@@ -47,7 +66,16 @@ pub(crate) struct Volta {
 
 impl Volta {
     pub(crate) fn run(self, session: &mut Session) -> Fallible<ExitCode> {
-        path::ensure_volta_dirs_exist()?;
+        match &self.command {
+            // `volta doctor` only repairs the existing layout, which under `sudo` is the one in
+            // the home directory of the user who ran `sudo`
+            Some(Subcommand::Doctor(_)) => permissions::use_sudo_user_home(),
+            Some(command) if command.modifies_layout() => {
+                permissions::check_layout()?;
+                path::ensure_layout()?;
+            }
+            _ => path::ensure_layout()?,
+        }
         if self.version {
             println!("{}", env!("CARGO_PKG_VERSION"));
             Ok(ExitCode::Success)
@@ -136,6 +164,76 @@ otherwise, they will be written to `stdout`.
     #[structopt(name = "which", author = "", version = "")]
     Which(command::Which),
 
+    /// Manages the cache of fetched tools
+    #[structopt(
+        name = "cache",
+        author = "",
+        version = "",
+        raw(setting = "structopt::clap::AppSettings::SubcommandRequiredElseHelp")
+    )]
+    Cache(command::Cache),
+
+    /// Manages aliases, which run a specific version of Node or Yarn by name
+    #[structopt(
+        name = "alias",
+        author = "",
+        version = "",
+        raw(setting = "structopt::clap::AppSettings::SubcommandRequiredElseHelp")
+    )]
+    Alias(command::Alias),
+
+    /// Imports a Node installation from outside of Volta, like a system-provided Node
+    #[structopt(
+        name = "import-node",
+        author = "",
+        version = "",
+        long_about = "Imports a Node installation from outside of Volta, like a system-provided Node
+
+The installation is linked rather than copied, so it must stay in place. Once imported, its
+version can be pinned and installed as the default like any other version of Node."
+    )]
+    ImportNode(command::ImportNode),
+
+    /// Sets up Volta's directories and shims
+    #[structopt(
+        name = "init",
+        author = "",
+        version = "",
+        long_about = "Sets up Volta's directories and shims
+
+Volta does this automatically the first time it runs, so this is only needed to prepare a
+new installation ahead of time or to repair one whose directories have been removed."
+    )]
+    Init(command::Init),
+
+    /// Prints a snapshot of your toolchain, to restore with `volta import`
+    #[structopt(
+        name = "export",
+        author = "",
+        version = "",
+        long_about = "Prints a snapshot of your toolchain, to restore with `volta import`
+
+The snapshot lists your default Node, npm, and Yarn, your installed packages, and your
+aliases, with their versions. Save it to a file with `volta export > toolchain.json`."
+    )]
+    Export(command::Export),
+
+    /// Installs everything in a toolchain file written by `volta export`
+    #[structopt(name = "import", author = "", version = "")]
+    Import(command::Import),
+
+    /// Checks the Volta directory for files with the wrong owner or permissions
+    #[structopt(
+        name = "doctor",
+        author = "",
+        version = "",
+        long_about = "Checks the Volta directory for files with the wrong owner or permissions
+
+Running Volta with `sudo` leaves files owned by root, which your user can no longer modify.
+Use `sudo volta doctor --fix-permissions` to give them back to the user who ran `sudo`."
+    )]
+    Doctor(command::Doctor),
+
     #[structopt(
         name = "use",
         author = "",
@@ -150,6 +248,24 @@ otherwise, they will be written to `stdout`.
 }
 
 impl Subcommand {
+    /// Whether the command can modify the Volta layout, and so needs its ownership checked
+    /// first. `volta doctor` reports and repairs these problems itself.
+    fn modifies_layout(&self) -> bool {
+        match self {
+            Subcommand::Fetch(_)
+            | Subcommand::Install(_)
+            | Subcommand::Uninstall(_)
+            | Subcommand::Pin(_)
+            | Subcommand::Cache(command::Cache::Clean(_))
+            | Subcommand::Alias(command::Alias::Create(_))
+            | Subcommand::Alias(command::Alias::Delete(_))
+            | Subcommand::ImportNode(_)
+            | Subcommand::Init(_)
+            | Subcommand::Import(_) => true,
+            _ => false,
+        }
+    }
+
     pub(crate) fn run(self, session: &mut Session) -> Fallible<ExitCode> {
         match self {
             Subcommand::Fetch(fetch) => fetch.run(session),
@@ -162,6 +278,13 @@ impl Subcommand {
             Subcommand::Activate(activate) => activate.run(session),
             Subcommand::Completions(completions) => completions.run(session),
             Subcommand::Which(which) => which.run(session),
+            Subcommand::Cache(cache) => cache.run(session),
+            Subcommand::Alias(alias) => alias.run(session),
+            Subcommand::ImportNode(import_node) => import_node.run(session),
+            Subcommand::Init(init) => init.run(session),
+            Subcommand::Export(export) => export.run(session),
+            Subcommand::Import(import) => import.run(session),
+            Subcommand::Doctor(doctor) => doctor.run(session),
             Subcommand::Use(r#use) => r#use.run(session),
         }
     }
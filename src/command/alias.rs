@@ -0,0 +1,120 @@
+use log::info;
+use structopt::StructOpt;
+
+use volta_core::alias;
+use volta_core::session::{ActivityKind, Session};
+use volta_core::style::success_prefix;
+use volta_core::tool::Spec;
+use volta_fail::{ExitCode, Fallible};
+
+use crate::command::Command;
+
+#[derive(StructOpt)]
+pub(crate) enum Alias {
+    /// Creates an alias for a version of Node or Yarn, e.g. `node16=node@16`
+    #[structopt(name = "create", author = "", version = "")]
+    Create(Create),
+
+    /// Lists your aliases
+    #[structopt(name = "list", author = "", version = "")]
+    List,
+
+    /// Deletes an alias
+    #[structopt(name = "delete", author = "", version = "")]
+    Delete(Delete),
+}
+
+#[derive(StructOpt)]
+pub(crate) struct Create {
+    /// The alias and the tool it runs, like `node16=node@16` or `yarn1=yarn@1`
+    #[structopt(name = "alias=tool[@version]", parse(try_from_str = "parse_alias"))]
+    alias: (String, String),
+}
+
+#[derive(StructOpt)]
+pub(crate) struct Delete {
+    /// The alias to delete
+    name: String,
+}
+
+impl Command for Alias {
+    fn run(self, session: &mut Session) -> Fallible<ExitCode> {
+        session.add_event_start(ActivityKind::Alias);
+
+        match self {
+            Alias::Create(Create {
+                alias: (name, tool),
+            }) => {
+                let spec = Spec::try_from_str(&tool)?;
+                let alias = alias::create(&name, spec, session)?;
+                info!("{} created alias {}", success_prefix(), alias);
+            }
+            Alias::List => {
+                let aliases = alias::list()?;
+                if aliases.is_empty() {
+                    info!("No aliases defined");
+                }
+                for alias in aliases {
+                    info!("{}", alias);
+                }
+            }
+            Alias::Delete(Delete { name }) => {
+                let alias = alias::delete(&name)?;
+                info!("{} deleted alias {}", success_prefix(), alias);
+            }
+        }
+
+        session.add_event_end(ActivityKind::Alias, ExitCode::Success);
+        Ok(ExitCode::Success)
+    }
+}
+
+/// Parses an alias definition such as `node16=node@16` into the alias name and the tool spec.
+/// The name becomes a shim file, so it is limited to letters, numbers, `-`, `_`, and `.`.
+fn parse_alias(definition: &str) -> Result<(String, String), String> {
+    let invalid = || {
+        format!(
+            "'{}' is not a valid alias (expected a name and a tool, e.g. `node16=node@16`)",
+            definition
+        )
+    };
+
+    let mut parts = definition.splitn(2, '=');
+    let name = parts.next().unwrap_or_default();
+    let tool = parts.next().ok_or_else(invalid)?;
+
+    let valid_name = name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.');
+    if name.is_empty() || name.starts_with('.') || !valid_name || tool.is_empty() {
+        return Err(invalid());
+    }
+
+    Ok((name.to_string(), tool.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_alias;
+
+    #[test]
+    fn test_parse_alias() {
+        assert_eq!(
+            parse_alias("node16=node@16"),
+            Ok(("node16".to_string(), "node@16".to_string()))
+        );
+        assert_eq!(
+            parse_alias("yarn-classic=yarn@^1.22"),
+            Ok(("yarn-classic".to_string(), "yarn@^1.22".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_alias() {
+        assert!(parse_alias("node16").is_err());
+        assert!(parse_alias("=node@16").is_err());
+        assert!(parse_alias("node16=").is_err());
+        assert!(parse_alias("../node16=node@16").is_err());
+        assert!(parse_alias(".node=node@16").is_err());
+    }
+}
@@ -0,0 +1,166 @@
+use std::time::Duration;
+
+use log::{info, warn};
+use structopt::StructOpt;
+
+use volta_core::cache::{self, CacheTool};
+use volta_core::error::ErrorDetails;
+use volta_core::path;
+use volta_core::session::{ActivityKind, Session};
+use volta_core::style::{human_bytes, success_prefix};
+use volta_fail::{throw, ExitCode, Fallible};
+
+use crate::command::Command;
+
+#[derive(StructOpt)]
+pub(crate) enum Cache {
+    /// Prints the directory where fetched tools are stored
+    #[structopt(name = "dir", author = "", version = "")]
+    Dir,
+
+    /// Displays the disk space used by fetched tools
+    #[structopt(name = "size", author = "", version = "")]
+    Size,
+
+    /// Removes fetched tools which are not part of your toolchain
+    #[structopt(name = "clean", author = "", version = "")]
+    Clean(Clean),
+
+    /// Checks fetched archives against their stored checksums
+    #[structopt(name = "verify", author = "", version = "")]
+    Verify,
+}
+
+#[derive(StructOpt)]
+pub(crate) struct Clean {
+    /// Only remove versions of this tool
+    #[structopt(
+        long = "tool",
+        raw(possible_values = r#"&["node", "npm", "yarn", "packages"]"#)
+    )]
+    tool: Option<CacheTool>,
+
+    /// Only remove versions fetched longer ago than this, e.g. `90d`, `2w`, or `12h`
+    #[structopt(long = "older-than", parse(try_from_str = "parse_age"))]
+    older_than: Option<Duration>,
+}
+
+impl Command for Cache {
+    fn run(self, session: &mut Session) -> Fallible<ExitCode> {
+        session.add_event_start(ActivityKind::Cache);
+
+        match self {
+            Cache::Dir => println!("{}", path::inventory_dir()?.display()),
+            Cache::Size => {
+                let size = cache::size()?;
+                info!("node:     {}", human_bytes(size.node));
+                info!("npm:      {}", human_bytes(size.npm));
+                info!("yarn:     {}", human_bytes(size.yarn));
+                info!("packages: {}", human_bytes(size.packages));
+                info!("images:   {}", human_bytes(size.images));
+                info!("total:    {}", human_bytes(size.total()));
+            }
+            Cache::Clean(Clean { tool, older_than }) => {
+                let report = cache::clean(session, tool, older_than)?;
+                if report.removed.is_empty() {
+                    info!("Nothing to clean");
+                } else {
+                    info!(
+                        "{} removed {} cached version(s), freeing {}",
+                        success_prefix(),
+                        report.removed.len(),
+                        human_bytes(report.freed)
+                    );
+                }
+                if !report.in_use.is_empty() {
+                    info!(
+                        "Kept {} version(s) which are still in use",
+                        report.in_use.len()
+                    );
+                }
+            }
+            Cache::Verify => {
+                let report = cache::verify(session)?;
+                if !report.corrupted.is_empty() {
+                    for entry in report.corrupted.iter() {
+                        warn!("{} does not match its stored checksum", entry);
+                    }
+                    throw!(ErrorDetails::CorruptedCacheArchives {
+                        count: report.corrupted.len(),
+                    });
+                }
+
+                info!(
+                    "{} verified {} cached archive(s)",
+                    success_prefix(),
+                    report.verified.len()
+                );
+                if !report.unchecked.is_empty() {
+                    info!(
+                        "Skipped {} version(s) without a stored checksum",
+                        report.unchecked.len()
+                    );
+                }
+            }
+        }
+
+        session.add_event_end(ActivityKind::Cache, ExitCode::Success);
+        Ok(ExitCode::Success)
+    }
+}
+
+/// Parses an age such as `90d` into a `Duration`. The supported units are `s`, `m`, `h`, `d`,
+/// and `w`.
+fn parse_age(age: &str) -> Result<Duration, String> {
+    let invalid = || {
+        format!(
+            "'{}' is not a valid age (expected a number followed by s, m, h, d, or w, e.g. `90d`)",
+            age
+        )
+    };
+
+    let split = age.len().saturating_sub(1);
+    if !age.is_char_boundary(split) {
+        return Err(invalid());
+    }
+    let (count, unit) = age.split_at(split);
+    let count: u64 = count.parse().map_err(|_| invalid())?;
+    let seconds = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => return Err(invalid()),
+    };
+
+    count
+        .checked_mul(seconds)
+        .map(Duration::from_secs)
+        .ok_or_else(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_age;
+    use std::time::Duration;
+
+    #[test]
+    fn test_parse_age() {
+        assert_eq!(parse_age("45s"), Ok(Duration::from_secs(45)));
+        assert_eq!(parse_age("30m"), Ok(Duration::from_secs(30 * 60)));
+        assert_eq!(parse_age("12h"), Ok(Duration::from_secs(12 * 60 * 60)));
+        assert_eq!(parse_age("90d"), Ok(Duration::from_secs(90 * 24 * 60 * 60)));
+        assert_eq!(parse_age("2w"), Ok(Duration::from_secs(14 * 24 * 60 * 60)));
+    }
+
+    #[test]
+    fn test_parse_invalid_age() {
+        assert!(parse_age("").is_err());
+        assert!(parse_age("d").is_err());
+        assert!(parse_age("90").is_err());
+        assert!(parse_age("90y").is_err());
+        assert!(parse_age("-1d").is_err());
+        assert!(parse_age("99999999999999999w").is_err());
+    }
+}
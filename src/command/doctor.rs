@@ -0,0 +1,56 @@
+use log::info;
+use structopt::StructOpt;
+
+use volta_core::permissions::{self, Problem};
+use volta_core::session::{ActivityKind, Session};
+use volta_core::style::success_prefix;
+use volta_fail::{ExitCode, Fallible};
+
+use crate::command::Command;
+
+#[derive(StructOpt)]
+pub(crate) struct Doctor {
+    /// Gives files owned by another user back to you, and makes directories writable
+    #[structopt(long = "fix-permissions")]
+    fix_permissions: bool,
+}
+
+impl Command for Doctor {
+    fn run(self, session: &mut Session) -> Fallible<ExitCode> {
+        session.add_event_start(ActivityKind::Doctor);
+
+        let exit_code = if self.fix_permissions {
+            let fixed = permissions::fix_problems()?;
+            info!(
+                "{} repaired the permissions of {} path(s)",
+                success_prefix(),
+                fixed
+            );
+            ExitCode::Success
+        } else {
+            let problems = permissions::find_problems()?;
+            if problems.is_empty() {
+                info!("No problems found");
+                ExitCode::Success
+            } else {
+                for problem in problems.iter() {
+                    info!("{}", problem);
+                }
+                // Only files owned by another user need `sudo` to be given back
+                let needs_sudo = problems.iter().any(|problem| match problem {
+                    Problem::NotOwned(_) => true,
+                    Problem::NotWritable(_) => false,
+                });
+                let command = if needs_sudo { "sudo volta" } else { "volta" };
+                info!(
+                    "\nTo fix these problems, run `{} doctor --fix-permissions`",
+                    command
+                );
+                ExitCode::FileSystemError
+            }
+        };
+
+        session.add_event_end(ActivityKind::Doctor, exit_code);
+        Ok(exit_code)
+    }
+}
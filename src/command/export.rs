@@ -0,0 +1,21 @@
+use structopt::StructOpt;
+
+use volta_core::session::{ActivityKind, Session};
+use volta_core::snapshot;
+use volta_fail::{ExitCode, Fallible};
+
+use crate::command::Command;
+
+#[derive(StructOpt)]
+pub(crate) struct Export {}
+
+impl Command for Export {
+    fn run(self, session: &mut Session) -> Fallible<ExitCode> {
+        session.add_event_start(ActivityKind::Export);
+
+        println!("{}", snapshot::export(session)?);
+
+        session.add_event_end(ActivityKind::Export, ExitCode::Success);
+        Ok(ExitCode::Success)
+    }
+}
@@ -0,0 +1,38 @@
+use std::path::PathBuf;
+
+use log::info;
+use structopt::StructOpt;
+
+use volta_core::session::{ActivityKind, Session};
+use volta_core::snapshot;
+use volta_core::style::success_prefix;
+use volta_fail::{ExitCode, Fallible};
+
+use crate::command::Command;
+
+#[derive(StructOpt)]
+pub(crate) struct Import {
+    /// The toolchain file written by `volta export`
+    #[structopt(parse(from_os_str))]
+    file: PathBuf,
+
+    /// Fails if any version in the file is not exact, instead of resolving it
+    #[structopt(long = "frozen")]
+    frozen: bool,
+}
+
+impl Command for Import {
+    fn run(self, session: &mut Session) -> Fallible<ExitCode> {
+        session.add_event_start(ActivityKind::Import);
+
+        snapshot::import(&self.file, self.frozen, session)?;
+        info!(
+            "{} imported toolchain from {}",
+            success_prefix(),
+            self.file.display()
+        );
+
+        session.add_event_end(ActivityKind::Import, ExitCode::Success);
+        Ok(ExitCode::Success)
+    }
+}
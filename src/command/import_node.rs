@@ -0,0 +1,35 @@
+use std::path::PathBuf;
+
+use log::info;
+use structopt::StructOpt;
+
+use volta_core::session::{ActivityKind, Session};
+use volta_core::style::success_prefix;
+use volta_core::tool;
+use volta_fail::{ExitCode, Fallible};
+
+use crate::command::Command;
+
+#[derive(StructOpt)]
+pub(crate) struct ImportNode {
+    /// The directory of the Node installation, e.g. `/opt/node`
+    #[structopt(parse(from_os_str))]
+    path: PathBuf,
+}
+
+impl Command for ImportNode {
+    fn run(self, session: &mut Session) -> Fallible<ExitCode> {
+        session.add_event_start(ActivityKind::ImportNode);
+
+        let node_version = tool::import_external_node(&self.path)?;
+        info!(
+            "{} imported {} from {}",
+            success_prefix(),
+            node_version,
+            self.path.display()
+        );
+
+        session.add_event_end(ActivityKind::ImportNode, ExitCode::Success);
+        Ok(ExitCode::Success)
+    }
+}
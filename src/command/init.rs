@@ -0,0 +1,28 @@
+use log::info;
+use structopt::StructOpt;
+
+use volta_core::path;
+use volta_core::session::{ActivityKind, Session};
+use volta_core::style::success_prefix;
+use volta_fail::{ExitCode, Fallible};
+
+use crate::command::Command;
+
+#[derive(StructOpt)]
+pub(crate) struct Init {}
+
+impl Command for Init {
+    fn run(self, session: &mut Session) -> Fallible<ExitCode> {
+        session.add_event_start(ActivityKind::Init);
+
+        path::init_layout()?;
+        info!(
+            "{} initialized Volta in {}",
+            success_prefix(),
+            path::volta_home()?.display()
+        );
+
+        session.add_event_end(ActivityKind::Init, ExitCode::Success);
+        Ok(ExitCode::Success)
+    }
+}
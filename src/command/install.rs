@@ -11,6 +11,14 @@ pub(crate) struct Install {
     /// Tools to install, like `node`, `yarn@latest` or `your-package@^14.4.3`.
     #[structopt(name = "tool[@version]", required = true, min_values = 1)]
     tools: Vec<String>,
+
+    /// Also installs the executables of each package's direct dependencies
+    #[structopt(long = "with-dependencies-bins")]
+    with_dependencies_bins: bool,
+
+    /// Chooses a Node version from a list of the matching versions, instead of the newest
+    #[structopt(long = "interactive")]
+    interactive: bool,
 }
 
 impl Command for Install {
@@ -18,7 +26,15 @@ impl Command for Install {
         session.add_event_start(ActivityKind::Install);
 
         for tool in Spec::from_strings(&self.tools, "install")? {
-            tool.resolve(session)?.install(session)?;
+            let mut tool = if self.interactive {
+                tool.resolve_interactive(session)?
+            } else {
+                tool.resolve(session)?
+            };
+            if self.with_dependencies_bins {
+                tool = tool.with_dependencies_bins();
+            }
+            tool.install(session)?;
         }
 
         session.add_event_end(ActivityKind::Install, ExitCode::Success);
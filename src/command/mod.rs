@@ -1,8 +1,15 @@
 pub(crate) mod activate;
+pub(crate) mod alias;
+pub(crate) mod cache;
 pub(crate) mod completions;
 pub(crate) mod current;
 pub(crate) mod deactivate;
+pub(crate) mod doctor;
+pub(crate) mod export;
 pub(crate) mod fetch;
+pub(crate) mod import;
+pub(crate) mod import_node;
+pub(crate) mod init;
 pub(crate) mod install;
 pub(crate) mod list;
 pub(crate) mod pin;
@@ -13,10 +20,17 @@ pub(crate) mod which;
 
 pub(crate) use self::which::Which;
 pub(crate) use activate::Activate;
+pub(crate) use alias::Alias;
+pub(crate) use cache::Cache;
 pub(crate) use completions::Completions;
 pub(crate) use current::Current;
 pub(crate) use deactivate::Deactivate;
+pub(crate) use doctor::Doctor;
+pub(crate) use export::Export;
 pub(crate) use fetch::Fetch;
+pub(crate) use import::Import;
+pub(crate) use import_node::ImportNode;
+pub(crate) use init::Init;
 pub(crate) use install::Install;
 pub(crate) use list::List;
 pub(crate) use pin::Pin;
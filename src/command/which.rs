@@ -37,8 +37,14 @@ impl Command for Which {
                     _ => None,
                 });
 
+        let cwd = env::current_dir().with_context(|_| ErrorDetails::CurrentDirError)?;
         let tool_path = match (user_tool, project_bin_path) {
             (Some(_), Some(project_bin_dir)) => Some(project_bin_dir.join(&bin)),
+            // An alias only names its tool, which is found in the alias's platform image
+            (Some(ref tool), _) if tool.bin_path.is_relative() => {
+                let path = tool.platform.checkout(session)?.path()?;
+                which_in(&tool.bin_path, Some(path), &cwd).ok()
+            }
             (Some(tool), _) => Some(tool.bin_path),
             _ => None,
         };
@@ -64,7 +70,6 @@ impl Command for Which {
             None => System::path()?,
         };
 
-        let cwd = env::current_dir().with_context(|_| ErrorDetails::CurrentDirError)?;
         let exit_code = match which_in(&bin, Some(path), cwd) {
             Ok(result) => {
                 println!("{}", result.to_string_lossy());
@@ -2,11 +2,15 @@
 mod command;
 mod cli;
 
+use std::env;
+
 use structopt::StructOpt;
 
+use volta_core::env as volta_env;
 use volta_core::error::report_error;
 use volta_core::log::{LogContext, LogVerbosity, Logger};
 use volta_core::session::{ActivityKind, Session};
+use volta_core::timing;
 
 /// The entry point for the `volta` CLI.
 pub fn main() {
@@ -21,6 +25,19 @@ pub fn main() {
     };
     Logger::init(LogContext::Volta, verbosity).expect("Only a single logger should be initialized");
 
+    if volta.timing {
+        timing::enable();
+    }
+
+    // The project directory is passed on through the environment, so that it is also used by
+    // any tools that Volta runs. It is made absolute in case they run in another directory.
+    if let Some(project) = &volta.project {
+        let project = env::current_dir()
+            .map(|dir| dir.join(project))
+            .unwrap_or_else(|_| project.clone());
+        env::set_var(volta_env::PROJECT, project);
+    }
+
     let mut session = Session::new();
     session.add_event_start(ActivityKind::Volta);
     let exit_code = volta.run(&mut session).unwrap_or_else(|err| {
@@ -29,6 +46,10 @@ pub fn main() {
         err.exit_code()
     });
 
+    if let Some(report) = timing::report() {
+        eprintln!("{}", report);
+    }
+
     session.add_event_end(ActivityKind::Volta, exit_code);
     session.exit(exit_code);
 }
@@ -3,6 +3,13 @@ use hamcrest2::assert_that;
 use hamcrest2::prelude::*;
 use test_support::matchers::execs;
 
+const PLATFORM_NODE_ONLY: &'static str = r#"{
+    "node": {
+        "runtime": "10.99.1040",
+        "npm": "6.2.26"
+    }
+}"#;
+
 #[test]
 fn empty_volta_home_is_auto_created() {
     let s = sandbox()
@@ -31,6 +38,7 @@ fn empty_volta_home_is_auto_created() {
     assert!(Sandbox::dir_exists(".volta/tools/inventory/packages"));
     assert!(Sandbox::dir_exists(".volta/tools/inventory/yarn"));
     assert!(Sandbox::dir_exists(".volta/tools/user"));
+    assert!(s.sandbox_dir(".volta/layout.v1").is_file());
 
     // shims should all be created
     // NOTE: this doesn't work in Windows, because the shim directory
@@ -45,35 +53,38 @@ fn empty_volta_home_is_auto_created() {
 }
 
 #[test]
-fn existing_volta_home_is_unchanged() {
-    let s = sandbox().build();
+fn partial_volta_home_is_completed() {
+    let s = sandbox()
+        .platform(PLATFORM_NODE_ONLY)
+        .env("VOLTA_SHIM", &shim_exe().to_string_lossy())
+        .build();
 
     // directories that are already created by the test framework
     assert!(Sandbox::dir_exists(".volta"));
     assert!(Sandbox::dir_exists(".volta/cache/node"));
     assert!(Sandbox::dir_exists(".volta/tmp"));
     assert!(Sandbox::dir_exists(".volta/tools/inventory/node"));
-    assert!(Sandbox::dir_exists(".volta/tools/inventory/packages"));
-    assert!(Sandbox::dir_exists(".volta/tools/inventory/yarn"));
 
-    // shims do not exist
+    // the rest of the layout is missing
+    assert!(!Sandbox::dir_exists(".volta/tools/image/yarn"));
+    assert!(!s.sandbox_dir(".volta/layout.v1").exists());
     assert!(!Sandbox::shim_exists("node"));
-    assert!(!Sandbox::shim_exists("npm"));
-    assert!(!Sandbox::shim_exists("yarn"));
 
-    // running volta should not create anything else
+    // running volta fills in the missing parts
     assert_that!(s.volta("--version"), execs().with_status(0));
 
-    // everything should be the same as before running the command
-    assert!(Sandbox::dir_exists(".volta"));
-    assert!(Sandbox::dir_exists(".volta/cache/node"));
-    assert!(Sandbox::dir_exists(".volta/tmp"));
-    assert!(Sandbox::dir_exists(".volta/tools/inventory/node"));
-    assert!(Sandbox::dir_exists(".volta/tools/inventory/packages"));
-    assert!(Sandbox::dir_exists(".volta/tools/inventory/yarn"));
+    assert!(Sandbox::dir_exists(".volta/tools/image/yarn"));
+    assert!(Sandbox::dir_exists(".volta/log"));
+    assert!(s.sandbox_dir(".volta/layout.v1").is_file());
 
-    assert!(!Sandbox::shim_exists("node"));
-    assert!(!Sandbox::shim_exists("yarn"));
-    assert!(!Sandbox::shim_exists("npm"));
-    assert!(!Sandbox::shim_exists("npx"));
+    #[cfg(unix)]
+    {
+        assert!(Sandbox::shim_exists("node"));
+        assert!(Sandbox::shim_exists("yarn"));
+        assert!(Sandbox::shim_exists("npm"));
+        assert!(Sandbox::shim_exists("npx"));
+    }
+
+    // existing files are left alone
+    assert!(s.sandbox_dir(".volta/tools/user/platform.json").is_file());
 }
@@ -0,0 +1,57 @@
+//! Exit codes are part of Volta's public interface, so these tests deliberately assert the
+//! numeric values rather than the `ExitCode` variants.
+
+use crate::support::sandbox::sandbox;
+use hamcrest2::assert_that;
+use hamcrest2::prelude::*;
+use test_support::matchers::execs;
+
+const BASIC_PACKAGE_JSON: &'static str = r#"{
+  "name": "test-package"
+}"#;
+
+const NODE_VERSION_INFO: &'static str = r#"[
+{"version":"v10.99.1040","npm":"6.2.26","lts": "Dubnium","files":["linux-x64","osx-x64-tar","win-x64-zip","win-x86-zip"]},
+{"version":"v9.27.6","npm":"5.6.17","lts": false,"files":["linux-x64","osx-x64-tar","win-x64-zip","win-x86-zip"]}
+]
+"#;
+
+#[test]
+fn success_exits_with_0() {
+    let s = sandbox().build();
+
+    assert_that!(s.volta("--version"), execs().with_status(0));
+}
+
+#[test]
+fn invalid_arguments_exit_with_3() {
+    let s = sandbox().build();
+
+    assert_that!(s.volta("use node@10"), execs().with_status(3));
+}
+
+#[test]
+fn no_version_match_exits_with_4() {
+    let s = sandbox()
+        .package_json(BASIC_PACKAGE_JSON)
+        .node_available_versions(NODE_VERSION_INFO)
+        .build();
+
+    assert_that!(s.volta("pin node@99"), execs().with_status(4));
+}
+
+#[test]
+fn network_error_exits_with_5() {
+    // The index is available but the distro is not, so the download fails
+    let s = sandbox().node_available_versions(NODE_VERSION_INFO).build();
+
+    assert_that!(s.volta("install node@10"), execs().with_status(5));
+}
+
+#[test]
+fn configuration_error_exits_with_8() {
+    // No package.json, so there is no project to pin in
+    let s = sandbox().node_available_versions(NODE_VERSION_INFO).build();
+
+    assert_that!(s.volta("pin node@10"), execs().with_status(8));
+}
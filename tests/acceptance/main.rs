@@ -4,10 +4,23 @@ mod support;
 
 mod autocreate_home_dir;
 mod corrupted_download;
+mod exit_codes;
 mod intercept_global_installs;
 mod merged_platform;
+mod npm_config_dirs;
+mod project_dir;
+mod shim_startup;
+mod timing_flag;
 mod verbose_errors;
+mod volta_alias;
+mod volta_cache;
 mod volta_current;
 mod volta_deactivate;
+mod volta_doctor;
+mod volta_export_import;
+mod volta_import_node;
+mod volta_init;
+mod volta_install;
 mod volta_pin;
 mod volta_uninstall;
+mod xdg_layout;
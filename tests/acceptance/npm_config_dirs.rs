@@ -0,0 +1,62 @@
+//! Tests that npm's global prefix and cache are redirected out of the Node image.
+
+#![cfg(unix)]
+
+use crate::support::sandbox::sandbox;
+use hamcrest2::assert_that;
+use hamcrest2::prelude::*;
+use test_support::matchers::execs;
+
+const PLATFORM_NODE_ONLY: &'static str = r#"{
+    "node": {
+        "runtime": "10.99.1040",
+        "npm": "6.2.26"
+    }
+}"#;
+
+const NPM_PRINTS_CONFIG: &'static str = "#!/bin/sh
+echo \"prefix=$npm_config_prefix\"
+echo \"cache=$npm_config_cache\"
+";
+
+#[test]
+fn npm_uses_volta_prefix_and_cache() {
+    let s = sandbox()
+        .platform(PLATFORM_NODE_ONLY)
+        .node_image("10.99.1040", "6.2.26")
+        .node_image_executable("10.99.1040", "6.2.26", "npm", NPM_PRINTS_CONFIG)
+        .build();
+
+    let mut npm = s.npm("--version");
+    npm.env_remove("npm_config_prefix")
+        .env_remove("NPM_CONFIG_PREFIX")
+        .env_remove("npm_config_cache")
+        .env_remove("NPM_CONFIG_CACHE");
+
+    assert_that!(
+        npm,
+        execs()
+            .with_status(0)
+            .with_stdout_contains("prefix=[..]/.volta/tools/user/npm")
+            .with_stdout_contains("cache=[..]/.volta/cache/npm")
+    );
+}
+
+#[test]
+fn npm_keeps_user_prefix_and_cache() {
+    let s = sandbox()
+        .platform(PLATFORM_NODE_ONLY)
+        .node_image("10.99.1040", "6.2.26")
+        .node_image_executable("10.99.1040", "6.2.26", "npm", NPM_PRINTS_CONFIG)
+        .env("npm_config_prefix", "/custom/prefix")
+        .env("npm_config_cache", "/custom/cache")
+        .build();
+
+    assert_that!(
+        s.npm("--version"),
+        execs()
+            .with_status(0)
+            .with_stdout_contains("prefix=/custom/prefix")
+            .with_stdout_contains("cache=/custom/cache")
+    );
+}
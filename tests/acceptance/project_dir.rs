@@ -0,0 +1,93 @@
+use crate::support::sandbox::sandbox;
+use hamcrest2::assert_that;
+use hamcrest2::prelude::*;
+use test_support::matchers::execs;
+
+use volta_fail::ExitCode;
+
+const PACKAGE_JSON_WITH_YARN: &'static str = r#"{
+    "name": "with-yarn",
+    "volta": {
+        "node": "10.22.123",
+        "yarn": "4.55.633"
+    }
+}"#;
+
+const PLATFORM_WITH_YARN: &'static str = r#"{
+    "node":{
+        "runtime":"9.11.2",
+        "npm":"5.6.0"
+    },
+    "yarn": "1.22.300"
+}"#;
+
+#[test]
+fn shim_uses_project_from_env() {
+    let s = sandbox()
+        .platform(PLATFORM_WITH_YARN)
+        .project_package_json("projects/with-yarn", PACKAGE_JSON_WITH_YARN)
+        .env("VOLTA_LOGLEVEL", "debug")
+        .build();
+    let project_dir = s.sandbox_dir("projects/with-yarn");
+
+    let mut yarn = s.yarn("--version");
+    yarn.env("VOLTA_PROJECT", &project_dir);
+
+    assert_that!(
+        yarn,
+        execs()
+            .with_status(ExitCode::ExecutionFailure as i32)
+            .with_stderr_contains("[..]Using yarn@4.55.633 from project configuration")
+    );
+}
+
+#[test]
+fn shim_ignores_project_outside_of_project_dir() {
+    let s = sandbox()
+        .platform(PLATFORM_WITH_YARN)
+        .package_json(PACKAGE_JSON_WITH_YARN)
+        .env("VOLTA_LOGLEVEL", "debug")
+        .build();
+    let other_dir = s.sandbox_dir(".volta");
+
+    let mut yarn = s.yarn("--version");
+    yarn.env("VOLTA_PROJECT", &other_dir);
+
+    assert_that!(
+        yarn,
+        execs()
+            .with_status(ExitCode::ExecutionFailure as i32)
+            .with_stderr_contains("[..]Using yarn@1.22.300 from default configuration")
+    );
+}
+
+#[test]
+fn project_flag_selects_project() {
+    let s = sandbox()
+        .platform(PLATFORM_WITH_YARN)
+        .project_package_json("projects/with-yarn", PACKAGE_JSON_WITH_YARN)
+        .build();
+    let project_dir = s.sandbox_dir("projects/with-yarn");
+
+    assert_that!(
+        s.volta(&format!(
+            "list node --current --format plain --project {}",
+            project_dir.display()
+        )),
+        execs()
+            .with_status(0)
+            .with_stdout_contains("runtime node@10.22.123 (current @ [..]package.json)")
+    );
+}
+
+#[test]
+fn project_flag_with_missing_dir() {
+    let s = sandbox().build();
+
+    assert_that!(
+        s.volta("--project missing list"),
+        execs()
+            .with_status(ExitCode::InvalidArguments as i32)
+            .with_stderr_contains("[..]Could not find project directory[..]")
+    );
+}
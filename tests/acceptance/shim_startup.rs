@@ -0,0 +1,123 @@
+//! A benchmark harness for the cold-start time of the shim on the happy path: running the
+//! default Node version, which has already been fetched and unpacked.
+//!
+//! The timings are only meaningful for optimized builds, so this is ignored by default.
+//! To run it locally:
+//!
+//! ```sh
+//! cargo test --release --features mock-network --test acceptance shim_startup -- --ignored --nocapture
+//! ```
+//!
+//! The overhead budget defaults to 5 milliseconds, and can be changed by setting
+//! `VOLTA_SHIM_BUDGET_MS`.
+
+#![cfg(unix)]
+
+use std::env;
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use crate::support::sandbox::sandbox;
+
+const PLATFORM_NODE_ONLY: &'static str = r#"{
+    "node": {
+        "runtime": "10.99.1040",
+        "npm": "6.2.26"
+    }
+}"#;
+
+const PACKAGE_JSON_WITH_PIN: &'static str = r#"{
+  "name": "benchmark-project",
+  "dependencies": {
+    "lodash": "^4.17.15"
+  },
+  "volta": {
+    "node": "10.99.1040"
+  }
+}"#;
+
+const RUNS: usize = 50;
+const DEFAULT_BUDGET_MS: u64 = 5;
+
+/// Runs the command repeatedly, returning the median time for a single run.
+fn median_time(command: &mut Command) -> Duration {
+    let mut times: Vec<Duration> = (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            let status = command.status().expect("Could not run benchmark command");
+            let elapsed = start.elapsed();
+            assert!(status.success(), "Benchmark command failed: {:?}", command);
+            elapsed
+        })
+        .collect();
+
+    times.sort();
+    times[RUNS / 2]
+}
+
+fn budget() -> Duration {
+    let millis = env::var("VOLTA_SHIM_BUDGET_MS")
+        .ok()
+        .and_then(|budget| budget.parse().ok())
+        .unwrap_or(DEFAULT_BUDGET_MS);
+    Duration::from_millis(millis)
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs() as f64 * 1000.0 + f64::from(duration.subsec_micros()) / 1000.0
+}
+
+/// Measures the time the shim adds on top of running the tool directly.
+fn assert_overhead_within_budget(name: &str, shim: &mut Command, direct: &mut Command) {
+    let shim_time = median_time(shim);
+    let direct_time = median_time(direct);
+    let overhead = shim_time.checked_sub(direct_time).unwrap_or_default();
+
+    println!(
+        "{}: shim {:.2}ms, direct {:.2}ms, overhead {:.2}ms",
+        name,
+        millis(shim_time),
+        millis(direct_time),
+        millis(overhead)
+    );
+
+    assert!(
+        overhead <= budget(),
+        "{}: the shim took {:.2}ms longer than running node directly (budget {:.2}ms)",
+        name,
+        millis(overhead),
+        millis(budget())
+    );
+}
+
+#[test]
+#[ignore]
+fn shim_startup_default_platform() {
+    let s = sandbox()
+        .platform(PLATFORM_NODE_ONLY)
+        .node_image("10.99.1040", "6.2.26")
+        .build();
+
+    let mut shim = s.node("--version").build_command();
+    let node_bin = s.node_image_bin_dir("10.99.1040", "6.2.26").join("node");
+    let mut direct = s.process(&node_bin).build_command();
+    direct.arg("--version");
+
+    assert_overhead_within_budget("default platform", &mut shim, &mut direct);
+}
+
+#[test]
+#[ignore]
+fn shim_startup_project_platform() {
+    let s = sandbox()
+        .package_json(PACKAGE_JSON_WITH_PIN)
+        .node_image("10.99.1040", "6.2.26")
+        .build();
+
+    let mut shim = s.node("--version").build_command();
+    let node_bin = s.node_image_bin_dir("10.99.1040", "6.2.26").join("node");
+    let mut direct = s.process(&node_bin).build_command();
+    direct.arg("--version");
+
+    assert_overhead_within_budget("project platform", &mut shim, &mut direct);
+}
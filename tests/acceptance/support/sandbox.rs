@@ -87,6 +87,7 @@ impl EnvVar {
 pub struct FileBuilder {
     path: PathBuf,
     contents: String,
+    executable: bool,
 }
 
 impl FileBuilder {
@@ -94,6 +95,15 @@ impl FileBuilder {
         FileBuilder {
             path,
             contents: contents.to_string(),
+            executable: false,
+        }
+    }
+
+    pub fn executable(path: PathBuf, contents: &str) -> FileBuilder {
+        FileBuilder {
+            path,
+            contents: contents.to_string(),
+            executable: true,
         }
     }
 
@@ -104,6 +114,14 @@ impl FileBuilder {
             .unwrap_or_else(|e| panic!("could not create file {}: {}", self.path.display(), e));
 
         ok_or_panic! { file.write_all(self.contents.as_bytes()) };
+
+        #[cfg(unix)]
+        {
+            if self.executable {
+                use std::os::unix::fs::PermissionsExt;
+                ok_or_panic! { fs::set_permissions(&self.path, fs::Permissions::from_mode(0o755)) };
+            }
+        }
     }
 
     fn dirname(&self) -> &Path {
@@ -231,6 +249,13 @@ impl SandboxBuilder {
         self
     }
 
+    /// Set the package.json for a project in another directory of the sandbox (chainable)
+    pub fn project_package_json(mut self, dir: &str, contents: &str) -> Self {
+        let package_file = package_json_file(sandbox_dir(dir));
+        self.files.push(FileBuilder::new(package_file, contents));
+        self
+    }
+
     /// Set the platform.json for the sandbox (chainable)
     pub fn platform(mut self, contents: &str) -> Self {
         self.files
@@ -249,6 +274,12 @@ impl SandboxBuilder {
         self
     }
 
+    /// Remove an environment variable for the sandbox (chainable)
+    pub fn env_remove(mut self, name: &str) -> Self {
+        self.root.env_vars_remove.push(name.to_string());
+        self
+    }
+
     /// Add a directory to the PATH (chainable)
     pub fn path_dir(mut self, dir: &str) -> Self {
         self.path_dirs.push(PathBuf::from(dir));
@@ -397,6 +428,58 @@ impl SandboxBuilder {
         self
     }
 
+    /// Set a fetched and unpacked Node version, whose `node` executable exits immediately
+    /// (chainable)
+    #[cfg(unix)]
+    pub fn node_image(mut self, version: &str, npm: &str) -> Self {
+        self.files.push(FileBuilder::new(
+            node_inventory_dir().join(node_distro_file_name(version)),
+            "contents don't matter",
+        ));
+        self.files.push(FileBuilder::new(
+            node_inventory_dir().join(format!("node-v{}-npm", version)),
+            npm,
+        ));
+        self.files.push(FileBuilder::executable(
+            node_image_bin_dir(version, npm).join("node"),
+            "#!/bin/sh\nexit 0\n",
+        ));
+        self
+    }
+
+    /// Setup an executable in the bin directory of a Node image (chainable)
+    #[cfg(unix)]
+    pub fn node_image_executable(
+        mut self,
+        version: &str,
+        npm: &str,
+        name: &str,
+        contents: &str,
+    ) -> Self {
+        self.files.push(FileBuilder::executable(
+            node_image_bin_dir(version, npm).join(name),
+            contents,
+        ));
+        self
+    }
+
+    /// Setup a Node installation outside of Volta, in a directory of the sandbox
+    pub fn external_node(mut self, dir: &str, version: &str, npm: &str) -> Self {
+        let root = sandbox_dir(dir);
+        self.files.push(FileBuilder::executable(
+            root.join("bin").join("node"),
+            &format!("#!/bin/sh\necho v{}\n", version),
+        ));
+        self.files.push(FileBuilder::new(
+            root.join("lib")
+                .join("node_modules")
+                .join("npm")
+                .join("package.json"),
+            &format!(r#"{{"name": "npm", "version": "{}"}}"#, npm),
+        ));
+        self
+    }
+
     /// Create the project
     pub fn build(mut self) -> Sandbox {
         // First, clean the directory if it already exists
@@ -413,6 +496,7 @@ impl SandboxBuilder {
         ok_or_panic! { fs::create_dir_all(volta_tmp_dir()) };
 
         // Make sure the shims to npm and yarn exist
+        ok_or_panic! { create_file_symlink(shim_exe(), self.root.node_exe()) };
         ok_or_panic! { create_file_symlink(shim_exe(), self.root.npm_exe()) };
         ok_or_panic! { create_file_symlink(shim_exe(), self.root.yarn_exe()) };
 
@@ -493,6 +577,10 @@ fn node_index_file() -> PathBuf {
 fn node_index_expiry_file() -> PathBuf {
     node_cache_dir().join("index.json.expires")
 }
+#[cfg(unix)]
+fn node_image_bin_dir(version: &str, npm: &str) -> PathBuf {
+    image_dir().join("node").join(version).join(npm).join("bin")
+}
 fn package_json_file(mut root: PathBuf) -> PathBuf {
     root.push("package.json");
     root
@@ -569,6 +657,20 @@ impl Sandbox {
         p
     }
 
+    /// Create a `ProcessBuilder` to run the volta node shim.
+    /// Arguments can be separated by spaces.
+    /// Example:
+    ///     assert_that(p.node("--version"), execs());
+    pub fn node(&self, cmd: &str) -> ProcessBuilder {
+        let mut p = self.process(&self.node_exe());
+        split_and_add_args(&mut p, cmd);
+        p
+    }
+
+    pub fn node_exe(&self) -> PathBuf {
+        self.root().join(format!("node{}", env::consts::EXE_SUFFIX))
+    }
+
     /// Create a `ProcessBuilder` to run the volta npm shim.
     /// Arguments can be separated by spaces.
     /// Example:
@@ -638,9 +740,19 @@ impl Sandbox {
     pub fn shim_exists(name: &str) -> bool {
         shim_file(name).exists()
     }
+    pub fn shim_file(&self, name: &str) -> PathBuf {
+        shim_file(name)
+    }
     pub fn dir_exists(dir_path: &str) -> bool {
         sandbox_dir(dir_path).exists()
     }
+    pub fn sandbox_dir(&self, dir_path: &str) -> PathBuf {
+        sandbox_dir(dir_path)
+    }
+    #[cfg(unix)]
+    pub fn node_image_bin_dir(&self, version: &str, npm: &str) -> PathBuf {
+        node_image_bin_dir(version, npm)
+    }
     pub fn package_image_exists(name: &str, version: &str) -> bool {
         let package_img_dir = package_image_dir(name, version);
         package_img_dir.join("package.json").exists()
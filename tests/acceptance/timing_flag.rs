@@ -0,0 +1,39 @@
+use crate::support::sandbox::sandbox;
+use hamcrest2::assert_that;
+use hamcrest2::prelude::*;
+use test_support::matchers::execs;
+
+const PACKAGE_JSON: &'static str = r#"{
+  "name": "test-package",
+  "volta": {
+    "node": "4.1.0",
+    "npm": "2.14.3"
+  }
+}"#;
+
+#[test]
+fn timing_flag_prints_breakdown() {
+    let s = sandbox().package_json(PACKAGE_JSON).build();
+
+    assert_that!(
+        s.volta("current --timing"),
+        execs()
+            .with_status(0)
+            .with_stdout_contains("project: v4.1.0 (active)")
+            .with_stderr_contains("phase[..]count[..]time[..]share")
+            .with_stderr_contains("project detection[..]")
+            .with_stderr_contains("total[..]ms")
+    );
+}
+
+#[test]
+fn no_breakdown_without_timing_flag() {
+    let s = sandbox().package_json(PACKAGE_JSON).build();
+
+    assert_that!(
+        s.volta("current"),
+        execs()
+            .with_status(0)
+            .with_stderr_does_not_contain("[..]project detection[..]")
+    );
+}
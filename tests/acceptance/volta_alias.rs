@@ -0,0 +1,150 @@
+use crate::support::sandbox::{sandbox, shim_exe, Sandbox};
+use hamcrest2::assert_that;
+use hamcrest2::prelude::*;
+use test_support::matchers::execs;
+
+use volta_fail::ExitCode;
+
+const VOLTA_LOGLEVEL: &'static str = "VOLTA_LOGLEVEL";
+
+const NODE_ALIAS_CONFIG: &'static str = r#"{
+  "name": "node10",
+  "package": "node",
+  "version": "10.99.1040",
+  "path": "node",
+  "platform": {
+    "node": {
+      "runtime": "10.99.1040",
+      "npm": null
+    },
+    "yarn": null
+  },
+  "alias": true
+}"#;
+
+const COWSAY_BIN_CONFIG: &'static str = r#"{
+  "name": "cowsay",
+  "package": "cowsay",
+  "version": "1.4.0",
+  "path": "./cli.js",
+  "platform": {
+    "node": {
+      "runtime": "11.10.1",
+      "npm": "6.7.0"
+    },
+    "yarn": null
+  }
+}"#;
+
+#[test]
+#[cfg(unix)]
+fn create_node_alias() {
+    let s = sandbox()
+        .node_image("10.99.1040", "6.2.26")
+        .env(VOLTA_LOGLEVEL, "info")
+        .env("VOLTA_SHIM", &shim_exe().to_string_lossy())
+        .build();
+
+    assert_that!(
+        s.volta("alias create node10=node@10.99.1040"),
+        execs()
+            .with_status(0)
+            .with_stdout_contains("[..]created alias node10 -> node@10.99.1040")
+    );
+
+    assert!(Sandbox::bin_config_exists("node10"));
+    assert!(Sandbox::shim_exists("node10"));
+
+    // The alias runs the node from its own image, even with no default platform
+    assert_that!(s.process(s.shim_file("node10")), execs().with_status(0));
+}
+
+#[test]
+fn list_aliases() {
+    let s = sandbox()
+        .binary_config("node10", NODE_ALIAS_CONFIG)
+        .binary_config("cowsay", COWSAY_BIN_CONFIG)
+        .env(VOLTA_LOGLEVEL, "info")
+        .build();
+
+    assert_that!(
+        s.volta("alias list"),
+        execs()
+            .with_status(0)
+            .with_stdout_contains("node10 -> node@10.99.1040")
+            .with_stdout_does_not_contain("[..]cowsay[..]")
+    );
+}
+
+#[test]
+fn delete_alias() {
+    let s = sandbox()
+        .binary_config("node10", NODE_ALIAS_CONFIG)
+        .shim("node10")
+        .env(VOLTA_LOGLEVEL, "info")
+        .build();
+
+    assert_that!(
+        s.volta("alias delete node10"),
+        execs()
+            .with_status(0)
+            .with_stdout_contains("[..]deleted alias node10 -> node@10.99.1040")
+    );
+
+    assert!(!Sandbox::bin_config_exists("node10"));
+    assert!(!Sandbox::shim_exists("node10"));
+}
+
+#[test]
+fn delete_package_binary_is_not_an_alias() {
+    let s = sandbox()
+        .binary_config("cowsay", COWSAY_BIN_CONFIG)
+        .shim("cowsay")
+        .build();
+
+    assert_that!(
+        s.volta("alias delete cowsay"),
+        execs()
+            .with_status(ExitCode::InvalidArguments as i32)
+            .with_stderr_contains("[..]Could not find alias 'cowsay'")
+    );
+
+    assert!(Sandbox::bin_config_exists("cowsay"));
+    assert!(Sandbox::shim_exists("cowsay"));
+}
+
+#[test]
+fn create_alias_for_built_in_shim() {
+    let s = sandbox().build();
+
+    assert_that!(
+        s.volta("alias create node=node@10.99.1040"),
+        execs()
+            .with_status(ExitCode::InvalidArguments as i32)
+            .with_stderr_contains("[..]Cannot create an alias named \"node\"[..]")
+    );
+}
+
+#[test]
+fn create_alias_conflicting_with_package_binary() {
+    let s = sandbox().binary_config("cowsay", COWSAY_BIN_CONFIG).build();
+
+    assert_that!(
+        s.volta("alias create cowsay=node@10.99.1040"),
+        execs()
+            .with_status(ExitCode::InvalidArguments as i32)
+            .with_stderr_contains("[..]Executable 'cowsay' is already installed by cowsay")
+    );
+}
+
+#[test]
+fn create_alias_for_package() {
+    let s = sandbox().build();
+
+    assert_that!(
+        s.volta("alias create say=cowsay@1.4.0"),
+        execs()
+            .with_status(ExitCode::InvalidArguments as i32)
+            .with_stderr_contains("[..]Only node and yarn can be aliased")
+    );
+}
@@ -0,0 +1,89 @@
+use crate::support::sandbox::{sandbox, Sandbox};
+use hamcrest2::assert_that;
+use hamcrest2::prelude::*;
+use test_support::matchers::execs;
+
+use volta_fail::ExitCode;
+
+const PKG_CONFIG_BASIC: &'static str = r#"{
+  "name": "cowsay",
+  "version": "1.4.0",
+  "platform": {
+    "node": {
+      "runtime": "11.10.1",
+      "npm": "6.7.0"
+    },
+    "yarn": null
+  },
+  "bins": [
+    "cowsay",
+    "cowthink"
+  ]
+}"#;
+
+const VOLTA_LOGLEVEL: &'static str = "VOLTA_LOGLEVEL";
+
+#[test]
+fn cache_dir_prints_inventory() {
+    let s = sandbox().build();
+
+    assert_that!(
+        s.volta("cache dir"),
+        execs().with_status(0).with_stdout_contains("[..]inventory")
+    );
+}
+
+#[test]
+fn cache_clean_removes_uninstalled_packages() {
+    let s = sandbox()
+        .package_image("cowsay", "1.4.0")
+        .package_inventory("cowsay", "1.4.0")
+        .env(VOLTA_LOGLEVEL, "info")
+        .build();
+
+    assert_that!(
+        s.volta("cache clean --tool packages"),
+        execs()
+            .with_status(0)
+            .with_stdout_contains("[..]removed 1 cached version(s)[..]")
+    );
+
+    assert!(!Sandbox::package_image_exists("cowsay", "1.4.0"));
+    assert!(!Sandbox::pkg_inventory_tarball_exists("cowsay", "1.4.0"));
+    assert!(!Sandbox::pkg_inventory_shasum_exists("cowsay", "1.4.0"));
+}
+
+#[test]
+fn cache_clean_keeps_installed_packages() {
+    let s = sandbox()
+        .package_config("cowsay", PKG_CONFIG_BASIC)
+        .package_image("cowsay", "1.4.0")
+        .package_inventory("cowsay", "1.4.0")
+        .env(VOLTA_LOGLEVEL, "info")
+        .build();
+
+    assert_that!(
+        s.volta("cache clean"),
+        execs()
+            .with_status(0)
+            .with_stdout_contains("Nothing to clean")
+            .with_stdout_contains("Kept 1 version(s) which are still in use")
+    );
+
+    assert!(Sandbox::package_image_exists("cowsay", "1.4.0"));
+    assert!(Sandbox::pkg_inventory_tarball_exists("cowsay", "1.4.0"));
+    assert!(Sandbox::pkg_inventory_shasum_exists("cowsay", "1.4.0"));
+}
+
+#[test]
+fn cache_verify_reports_corrupted_archives() {
+    // the sandbox inventory files have placeholder contents, so the shasum won't match
+    let s = sandbox().package_inventory("cowsay", "1.4.0").build();
+
+    assert_that!(
+        s.volta("cache verify"),
+        execs()
+            .with_status(ExitCode::FileSystemError as i32)
+            .with_stderr_contains("[..]cowsay@1.4.0 does not match its stored checksum")
+    );
+}
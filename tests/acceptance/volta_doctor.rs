@@ -0,0 +1,66 @@
+//! Tests for `volta doctor`. Ownership can only be changed by root, so these cover the
+//! permissions of directories, which behave the same for every user.
+
+#![cfg(unix)]
+
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+
+use crate::support::sandbox::{sandbox, shim_exe};
+use hamcrest2::assert_that;
+use hamcrest2::prelude::*;
+use test_support::matchers::execs;
+
+use volta_fail::ExitCode;
+
+const VOLTA_LOGLEVEL: &'static str = "VOLTA_LOGLEVEL";
+
+#[test]
+fn doctor_finds_no_problems() {
+    let s = sandbox()
+        .env(VOLTA_LOGLEVEL, "info")
+        .env("VOLTA_SHIM", &shim_exe().to_string_lossy())
+        .build();
+
+    assert_that!(
+        s.volta("doctor"),
+        execs()
+            .with_status(0)
+            .with_stdout_contains("No problems found")
+    );
+}
+
+#[test]
+fn doctor_fixes_unwritable_directory() {
+    let s = sandbox()
+        .env(VOLTA_LOGLEVEL, "info")
+        .env("VOLTA_SHIM", &shim_exe().to_string_lossy())
+        .build();
+
+    assert_that!(s.volta("init"), execs().with_status(0));
+
+    let image_dir = s.sandbox_dir(".volta/tools/image");
+    fs::set_permissions(&image_dir, fs::Permissions::from_mode(0o555)).unwrap();
+
+    assert_that!(
+        s.volta("doctor"),
+        execs()
+            .with_status(ExitCode::FileSystemError as i32)
+            .with_stdout_contains("[..].volta/tools/image is not writable")
+            .with_stdout_contains("To fix these problems, run `volta doctor --fix-permissions`")
+    );
+
+    assert_that!(
+        s.volta("doctor --fix-permissions"),
+        execs()
+            .with_status(0)
+            .with_stdout_contains("[..]repaired the permissions of 1 path(s)")
+    );
+
+    assert_that!(
+        s.volta("doctor"),
+        execs()
+            .with_status(0)
+            .with_stdout_contains("No problems found")
+    );
+}
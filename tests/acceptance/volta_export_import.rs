@@ -0,0 +1,133 @@
+use std::fs;
+
+use crate::support::sandbox::{sandbox, Sandbox};
+use hamcrest2::assert_that;
+use hamcrest2::prelude::*;
+use test_support::matchers::execs;
+
+use volta_fail::ExitCode;
+
+const PLATFORM: &'static str = r#"{
+    "node": {
+        "runtime": "10.99.1040",
+        "npm": "6.2.26"
+    },
+    "yarn": null
+}"#;
+
+const PKG_CONFIG_COWSAY: &'static str = r#"{
+  "name": "cowsay",
+  "version": "1.4.0",
+  "platform": {
+    "node": {
+      "runtime": "10.99.1040",
+      "npm": "6.2.26"
+    },
+    "yarn": null
+  },
+  "bins": [
+    "cowsay"
+  ]
+}"#;
+
+const NODE_ALIAS_CONFIG: &'static str = r#"{
+  "name": "node10",
+  "package": "node",
+  "version": "10.99.1040",
+  "path": "node",
+  "platform": {
+    "node": {
+      "runtime": "10.99.1040",
+      "npm": null
+    },
+    "yarn": null
+  },
+  "alias": true
+}"#;
+
+const TOOLCHAIN_FILE: &'static str = r#"{
+  "node": {
+    "runtime": "10.99.1040",
+    "npm": "6.2.26"
+  },
+  "yarn": null,
+  "packages": {},
+  "aliases": {
+    "node10": "node@10.99.1040"
+  }
+}"#;
+
+const TOOLCHAIN_FILE_WITH_RANGE: &'static str = r#"{
+  "node": {
+    "runtime": "10",
+    "npm": null
+  }
+}"#;
+
+#[test]
+fn export_toolchain() {
+    let s = sandbox()
+        .platform(PLATFORM)
+        .package_config("cowsay", PKG_CONFIG_COWSAY)
+        .binary_config("node10", NODE_ALIAS_CONFIG)
+        .build();
+
+    assert_that!(
+        s.volta("export"),
+        execs()
+            .with_status(0)
+            .with_stdout_contains("[..]\"runtime\": \"10.99.1040\"[..]")
+            .with_stdout_contains("[..]\"npm\": \"6.2.26\"[..]")
+            .with_stdout_contains("[..]\"cowsay\": \"1.4.0\"[..]")
+            .with_stdout_contains("[..]\"node10\": \"node@10.99.1040\"[..]")
+    );
+}
+
+#[test]
+#[cfg(unix)]
+fn import_toolchain() {
+    let s = sandbox()
+        .node_image("10.99.1040", "6.2.26")
+        .env("VOLTA_LOGLEVEL", "info")
+        .build();
+    let toolchain_file = s.sandbox_dir("toolchain.json");
+    fs::write(&toolchain_file, TOOLCHAIN_FILE).unwrap();
+
+    assert_that!(
+        s.volta(&format!("import {}", toolchain_file.display())),
+        execs()
+            .with_status(0)
+            .with_stdout_contains(
+                "[..]installed and set node@10.99.1040 (with npm@6.2.26) as default"
+            )
+            .with_stdout_contains("[..]created alias node10 -> node@10.99.1040")
+    );
+
+    assert!(Sandbox::bin_config_exists("node10"));
+}
+
+#[test]
+fn import_frozen_with_version_range() {
+    let s = sandbox().build();
+    let toolchain_file = s.sandbox_dir("toolchain.json");
+    fs::write(&toolchain_file, TOOLCHAIN_FILE_WITH_RANGE).unwrap();
+
+    assert_that!(
+        s.volta(&format!("import --frozen {}", toolchain_file.display())),
+        execs()
+            .with_status(ExitCode::ConfigurationError as i32)
+            .with_stderr_contains("[..]Could not import node@[..] with --frozen")
+    );
+}
+
+#[test]
+fn import_missing_file() {
+    let s = sandbox().build();
+
+    assert_that!(
+        s.volta("import missing.json"),
+        execs()
+            .with_status(ExitCode::FileSystemError as i32)
+            .with_stderr_contains("[..]Could not read toolchain file")
+    );
+}
@@ -0,0 +1,73 @@
+use std::fs;
+
+use crate::support::sandbox::sandbox;
+use hamcrest2::assert_that;
+use hamcrest2::prelude::*;
+use test_support::matchers::execs;
+
+use volta_fail::ExitCode;
+
+const VOLTA_LOGLEVEL: &'static str = "VOLTA_LOGLEVEL";
+
+#[test]
+#[cfg(unix)]
+fn import_external_node() {
+    let s = sandbox()
+        .external_node("opt/node", "12.4.0", "6.9.0")
+        .env(VOLTA_LOGLEVEL, "info")
+        .build();
+    let external_dir = s.sandbox_dir("opt/node");
+
+    assert_that!(
+        s.volta(&format!("import-node {}", external_dir.display())),
+        execs()
+            .with_status(0)
+            .with_stdout_contains("[..]imported node@12.4.0 (with npm@6.9.0)[..]")
+    );
+
+    let image_bin_dir = s.node_image_bin_dir("12.4.0", "6.9.0");
+    let image_dir = image_bin_dir.parent().unwrap();
+    assert!(fs::symlink_metadata(image_dir)
+        .unwrap()
+        .file_type()
+        .is_symlink());
+    assert!(image_bin_dir.join("node").exists());
+
+    // The imported version can be installed as the default without fetching it
+    assert_that!(
+        s.volta("install node@12.4.0"),
+        execs()
+            .with_status(0)
+            .with_stdout_contains("[..]installed and set node@12.4.0 (with npm@6.9.0)[..]")
+    );
+}
+
+#[test]
+#[cfg(unix)]
+fn import_already_available_node() {
+    let s = sandbox()
+        .node_image("12.4.0", "6.9.0")
+        .external_node("opt/node", "12.4.0", "6.9.0")
+        .build();
+    let external_dir = s.sandbox_dir("opt/node");
+
+    assert_that!(
+        s.volta(&format!("import-node {}", external_dir.display())),
+        execs()
+            .with_status(ExitCode::InvalidArguments as i32)
+            .with_stderr_contains("[..]node@12.4.0 is already available[..]")
+    );
+}
+
+#[test]
+fn import_missing_node() {
+    let s = sandbox().build();
+    let missing_dir = s.sandbox_dir("opt/missing");
+
+    assert_that!(
+        s.volta(&format!("import-node {}", missing_dir.display())),
+        execs()
+            .with_status(ExitCode::InvalidArguments as i32)
+            .with_stderr_contains("[..]Could not find a Node installation in[..]")
+    );
+}
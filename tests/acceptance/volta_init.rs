@@ -0,0 +1,47 @@
+use crate::support::sandbox::{sandbox, shim_exe, Sandbox};
+use hamcrest2::assert_that;
+use hamcrest2::prelude::*;
+use test_support::matchers::execs;
+use test_support::paths::PathExt;
+
+const VOLTA_LOGLEVEL: &'static str = "VOLTA_LOGLEVEL";
+
+#[test]
+fn init_creates_layout() {
+    let s = sandbox()
+        .env(VOLTA_LOGLEVEL, "info")
+        .env("VOLTA_SHIM", &shim_exe().to_string_lossy())
+        .build();
+    s.remove_volta_home();
+
+    assert_that!(
+        s.volta("init"),
+        execs()
+            .with_status(0)
+            .with_stdout_contains("[..]initialized Volta in [..]")
+    );
+
+    assert!(Sandbox::dir_exists(".volta/bin"));
+    assert!(Sandbox::dir_exists(".volta/tools/image/packages"));
+    assert!(Sandbox::dir_exists(".volta/tools/user/bins"));
+    assert!(s.sandbox_dir(".volta/layout.v1").is_file());
+}
+
+#[test]
+fn init_repairs_layout() {
+    let s = sandbox()
+        .env("VOLTA_SHIM", &shim_exe().to_string_lossy())
+        .build();
+
+    assert_that!(s.volta("--version"), execs().with_status(0));
+    assert!(s.sandbox_dir(".volta/layout.v1").is_file());
+
+    // directories removed after the layout was created are only restored by `volta init`
+    s.sandbox_dir(".volta/tools/image").rm_rf();
+    assert_that!(s.volta("--version"), execs().with_status(0));
+    assert!(!Sandbox::dir_exists(".volta/tools/image"));
+
+    assert_that!(s.volta("init"), execs().with_status(0));
+    assert!(Sandbox::dir_exists(".volta/tools/image/node"));
+    assert!(Sandbox::dir_exists(".volta/tools/image/yarn"));
+}
@@ -0,0 +1,64 @@
+use crate::support::sandbox::sandbox;
+use hamcrest2::assert_that;
+use hamcrest2::prelude::*;
+use test_support::matchers::execs;
+
+use volta_fail::ExitCode;
+
+const VOLTA_LOGLEVEL: &'static str = "VOLTA_LOGLEVEL";
+
+const NODE_VERSION_INFO: &'static str = r#"[
+{"version":"v10.99.1040","npm":"6.2.26","date":"2019-06-04","lts": "Dubnium","files":["linux-x64","osx-x64-tar","win-x64-zip","win-x86-zip"]},
+{"version":"v10.98.1030","npm":"6.2.25","date":"2019-05-28","lts": "Dubnium","files":["linux-x64","osx-x64-tar","win-x64-zip","win-x86-zip"]},
+{"version":"v9.27.6","npm":"5.6.17","date":"2018-06-12","lts": false,"files":["linux-x64","osx-x64-tar","win-x64-zip","win-x86-zip"]}
+]
+"#;
+
+#[test]
+#[cfg(unix)]
+fn install_interactive_outside_terminal_uses_newest() {
+    let s = sandbox()
+        .node_available_versions(NODE_VERSION_INFO)
+        .node_image("10.99.1040", "6.2.26")
+        .env(VOLTA_LOGLEVEL, "info")
+        .build();
+
+    // Without a terminal to choose in, the newest matching version is installed as usual
+    assert_that!(
+        s.volta("install node@10 --interactive"),
+        execs().with_status(0).with_stdout_contains(
+            "[..]installed and set node@10.99.1040 (with npm@6.2.26) as default"
+        )
+    );
+}
+
+#[test]
+#[cfg(unix)]
+fn install_into_unwritable_image_dir_fails_early() {
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+
+    let s = sandbox()
+        .node_available_versions(NODE_VERSION_INFO)
+        .env(VOLTA_LOGLEVEL, "info")
+        .build();
+
+    assert_that!(s.volta("init"), execs().with_status(0));
+
+    let image_dir = s.sandbox_dir(".volta/tools/image");
+    fs::set_permissions(&image_dir, fs::Permissions::from_mode(0o555)).unwrap();
+
+    // Root can write to the directory anyway, so there is nothing to report
+    let writable = fs::write(image_dir.join("probe"), "").is_ok();
+    if !writable {
+        assert_that!(
+            s.volta("install node@10.99.1040"),
+            execs()
+                .with_status(ExitCode::FileSystemError as i32)
+                .with_stderr_contains("[..]Could not modify [..].volta/tools/image")
+                .with_stderr_contains("[..]chmod -R u+w [..].volta/tools/image")
+        );
+    }
+
+    fs::set_permissions(&image_dir, fs::Permissions::from_mode(0o755)).unwrap();
+}
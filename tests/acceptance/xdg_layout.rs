@@ -0,0 +1,71 @@
+//! Tests for selecting the XDG layout on Linux, which needs a sandbox without `VOLTA_HOME`,
+//! since that always selects the single directory layout.
+
+#![cfg(target_os = "linux")]
+
+use std::fs;
+
+use crate::support::sandbox::{sandbox, shim_exe, SandboxBuilder};
+use hamcrest2::assert_that;
+use hamcrest2::prelude::*;
+use test_support::matchers::execs;
+use test_support::paths::home;
+
+const PLATFORM_NODE_ONLY: &'static str = r#"{
+    "node": {
+        "runtime": "10.99.1040",
+        "npm": "6.2.26"
+    }
+}"#;
+
+/// A sandbox whose XDG base directories are the defaults inside the sandbox home.
+fn xdg_sandbox() -> SandboxBuilder {
+    sandbox()
+        .platform(PLATFORM_NODE_ONLY)
+        .env("HOME", &home().to_string_lossy())
+        .env("VOLTA_SHIM", &shim_exe().to_string_lossy())
+        .env_remove("XDG_DATA_HOME")
+        .env_remove("XDG_CACHE_HOME")
+        .env_remove("XDG_CONFIG_HOME")
+}
+
+#[test]
+fn selecting_xdg_migrates_volta_home() {
+    let s = xdg_sandbox()
+        .env("VOLTA_LAYOUT", "xdg")
+        .env_remove("VOLTA_HOME")
+        .build();
+
+    assert_that!(s.volta("--version"), execs().with_status(0));
+
+    assert!(!home().join(".volta").exists());
+    assert!(home()
+        .join(".local/share/volta/tools/user/platform.json")
+        .is_file());
+    assert!(home().join(".local/share/volta/layout.v1").is_file());
+    assert_eq!(
+        fs::read_to_string(home().join(".config/volta/layout")).unwrap(),
+        "xdg"
+    );
+
+    // The selection is remembered without `VOLTA_LAYOUT`
+    let mut volta = s.volta("--version");
+    volta.env_remove("VOLTA_LAYOUT");
+    assert_that!(volta, execs().with_status(0));
+    assert!(!home().join(".volta").exists());
+}
+
+#[test]
+fn volta_home_overrides_xdg_with_warning() {
+    let s = xdg_sandbox().env("VOLTA_LAYOUT", "xdg").build();
+
+    assert_that!(
+        s.volta("--version"),
+        execs()
+            .with_status(0)
+            .with_stderr_contains("[..]The XDG layout is selected, but VOLTA_HOME is set[..]")
+    );
+
+    assert!(home().join(".volta/tools/user/platform.json").is_file());
+    assert!(!home().join(".local/share/volta").exists());
+}
@@ -118,3 +118,24 @@ fn install_scoped_package() {
         execs().with_status(0).with_stdout_contains("5.12.4")
     );
 }
+
+#[test]
+fn install_package_with_dependencies_bins() {
+    let p = temp_project().build();
+
+    // have to install node first, because we need npm
+    assert_that!(p.volta("install node@10.4.1"), execs().with_status(0));
+
+    // nodemon depends on semver, which provides the `semver` executable
+    assert_that!(
+        p.volta("install nodemon@1.19.1 --with-dependencies-bins"),
+        execs().with_status(0)
+    );
+    assert_eq!(p.shim_exists("nodemon"), true);
+    assert_eq!(p.shim_exists("semver"), true);
+
+    assert_that!(
+        p.exec_shim("semver", "1.2.3"),
+        execs().with_status(0).with_stdout_contains("1.2.3")
+    );
+}